@@ -1,13 +1,15 @@
 use crate::{
     args::Args,
-    common::{Content, Style},
-    constants::TICK_VALUE_MS,
+    common::{Content, DigitFont, Style},
+    config::Settings,
+    constants::{APP_NAME, TICK_VALUE_MS},
     events::{Event, EventHandler, Events},
-    storage::AppStorage,
-    terminal::Terminal,
+    storage::{AppStorage, STORAGE_VERSION},
+    terminal::{self, Terminal},
     widgets::{
-        clock::{self, Clock, ClockArgs},
+        clock::{self, Clock, ClockArgs, ClockKind, Format, Mode as ClockMode},
         countdown::{Countdown, CountdownWidget},
+        counter::{Counter, CounterWidget},
         footer::Footer,
         header::Header,
         pomodoro::{Mode as PomodoroMode, Pomodoro, PomodoroArgs, PomodoroWidget},
@@ -19,11 +21,15 @@ use ratatui::{
     buffer::Buffer,
     crossterm::event::{KeyCode, KeyEvent},
     layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style as RatatuiStyle},
     widgets::{StatefulWidget, Widget},
 };
 use std::time::Duration;
 use tracing::debug;
 
+// number of ticks to flash the screen for (~0.5 sec. by a tick of 100ms)
+const FLASH_TICKS: u8 = 5;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Mode {
     Running,
@@ -38,13 +44,40 @@ pub struct App {
     countdown: Countdown,
     timer: Timer,
     pomodoro: Pomodoro,
+    counter: Counter,
     style: Style,
     with_decis: bool,
+    set_title: bool,
+    // latest title set to terminal
+    title: String,
+    flash: bool,
+    // ticks left to flash the screen
+    flash_ticks: u8,
+    show_progress_percent: bool,
+    mono_digit: bool,
+    emphasize_changes: bool,
+    font: DigitFont,
+    scale: u16,
+    background_is_dark: bool,
+    // defaults to restore all clocks with (see `reset_to_defaults`)
+    settings: Settings,
 }
 
 pub struct AppArgs {
     pub style: Style,
     pub with_decis: bool,
+    pub set_title: bool,
+    pub flash: bool,
+    pub show_progress_percent: bool,
+    pub mono_digit: bool,
+    pub emphasize_changes: bool,
+    pub font: DigitFont,
+    pub scale: u16,
+    pub fixed_format: Option<Format>,
+    pub presets: Vec<Duration>,
+    pub checkpoints: Vec<Duration>,
+    pub repeat: bool,
+    pub background_is_dark: bool,
     pub show_menu: bool,
     pub content: Content,
     pub pomodoro_mode: PomodoroMode,
@@ -55,16 +88,36 @@ pub struct AppArgs {
     pub initial_value_countdown: Duration,
     pub current_value_countdown: Duration,
     pub current_value_timer: Duration,
+    pub current_value_counter: u64,
 }
 
 /// Getting `AppArgs` by merging `Args` and `AppStorage`.
 /// `Args` wins btw.
 impl From<(Args, AppStorage)> for AppArgs {
     fn from((args, stg): (Args, AppStorage)) -> Self {
+        let countdown = args.countdown.or(args.duration);
+        let with_presets = !args.presets.is_empty();
         AppArgs {
             with_decis: args.decis || stg.with_decis,
+            set_title: args.title,
+            flash: args.flash,
+            show_progress_percent: args.percent,
+            mono_digit: args.mono_digit,
+            emphasize_changes: args.emphasize_changes,
+            font: args.font.unwrap_or(stg.font),
+            scale: args.scale,
+            fixed_format: args.fixed_format,
+            presets: args.presets,
+            checkpoints: args.checkpoints,
+            repeat: args.repeat,
+            background_is_dark: terminal::background_is_dark(),
             show_menu: stg.show_menu,
-            content: args.mode.unwrap_or(stg.content),
+            // a countdown given by `duration` or `presets` is shown, except another mode is set explicitly
+            content: args
+                .mode
+                .or(args.duration.map(|_| Content::Countdown))
+                .or(with_presets.then_some(Content::Countdown))
+                .unwrap_or(stg.content),
             style: args.style.unwrap_or(stg.style),
             pomodoro_mode: stg.pomodoro_mode,
             initial_value_work: args.work.unwrap_or(stg.inital_value_work),
@@ -73,10 +126,11 @@ impl From<(Args, AppStorage)> for AppArgs {
             initial_value_pause: args.pause.unwrap_or(stg.inital_value_pause),
             // invalidate `current_value_pause` if an initial value is set via args
             current_value_pause: args.pause.unwrap_or(stg.current_value_pause),
-            initial_value_countdown: args.countdown.unwrap_or(stg.inital_value_countdown),
+            initial_value_countdown: countdown.unwrap_or(stg.inital_value_countdown),
             // invalidate `current_value_countdown` if an initial value is set via args
-            current_value_countdown: args.countdown.unwrap_or(stg.current_value_countdown),
+            current_value_countdown: countdown.unwrap_or(stg.current_value_countdown),
             current_value_timer: stg.current_value_timer,
+            current_value_counter: stg.current_value_counter,
         }
     }
 }
@@ -93,30 +147,61 @@ impl App {
             current_value_pause,
             current_value_countdown,
             current_value_timer,
+            current_value_counter,
             content,
             with_decis,
+            set_title,
+            flash,
+            show_progress_percent,
+            mono_digit,
+            emphasize_changes,
+            font,
+            scale,
+            fixed_format,
+            presets,
+            checkpoints,
+            repeat,
+            background_is_dark,
             pomodoro_mode,
         } = args;
+        let settings = Settings {
+            style,
+            with_decis,
+            fixed_format,
+            countdown: initial_value_countdown,
+            checkpoints,
+            presets,
+            repeat,
+        };
+        let mut timer = Clock::<clock::Timer>::new(ClockArgs {
+            initial_value: Duration::ZERO,
+            current_value: current_value_timer,
+            tick_value: Duration::from_millis(TICK_VALUE_MS),
+            style,
+            with_decis,
+        });
+        timer.set_fixed_format(fixed_format);
         Self {
             mode: Mode::Running,
             content,
             show_menu,
             style,
             with_decis,
-            countdown: Countdown::new(Clock::<clock::Countdown>::new(ClockArgs {
-                initial_value: initial_value_countdown,
-                current_value: current_value_countdown,
-                tick_value: Duration::from_millis(TICK_VALUE_MS),
-                style,
-                with_decis,
-            })),
-            timer: Timer::new(Clock::<clock::Timer>::new(ClockArgs {
-                initial_value: Duration::ZERO,
-                current_value: current_value_timer,
-                tick_value: Duration::from_millis(TICK_VALUE_MS),
-                style,
-                with_decis,
-            })),
+            set_title,
+            title: String::new(),
+            flash,
+            flash_ticks: 0,
+            show_progress_percent,
+            mono_digit,
+            emphasize_changes,
+            font,
+            scale,
+            background_is_dark,
+            countdown: Countdown::new(Clock::<clock::Countdown>::from_config(
+                &settings,
+                current_value_countdown,
+            )),
+            timer: Timer::new(timer),
             pomodoro: Pomodoro::new(PomodoroArgs {
                 mode: pomodoro_mode,
                 initial_value_work,
@@ -125,7 +210,10 @@ impl App {
                 current_value_pause,
                 style,
                 with_decis,
+                fixed_format,
             }),
+            counter: Counter::new(0, current_value_counter, style),
+            settings,
         }
     }
 
@@ -137,17 +225,34 @@ impl App {
                     Content::Countdown => self.countdown.update(event.clone()),
                     Content::Timer => self.timer.update(event.clone()),
                     Content::Pomodoro => self.pomodoro.update(event.clone()),
+                    Content::Counter => self.counter.update(event.clone()),
                 } {
                     match unhandled {
                         Event::Render | Event::Resize => {
                             self.draw(&mut terminal)?;
+                            if self.set_title {
+                                self.update_title()?;
+                            }
                         }
                         Event::Key(key) => self.handle_key_event(key),
                         _ => {}
                     }
                 }
+                self.update_flash(&event);
             }
         }
+        let countdown = self.countdown.get_clock();
+        debug!(
+            "Countdown has been running {:?} of {:?}",
+            countdown.active_elapsed(),
+            countdown.wall_elapsed()
+        );
+        let timer = self.timer.get_clock();
+        debug!(
+            "Timer has been running {:?} of {:?}",
+            timer.active_elapsed(),
+            timer.wall_elapsed()
+        );
         Ok(self)
     }
 
@@ -160,6 +265,7 @@ impl App {
             Content::Countdown => self.countdown.get_clock().is_edit_mode(),
             Content::Timer => self.timer.get_clock().is_edit_mode(),
             Content::Pomodoro => self.pomodoro.get_clock().is_edit_mode(),
+            Content::Counter => false,
         }
     }
 
@@ -168,6 +274,39 @@ impl App {
             Content::Countdown => self.countdown.get_clock().is_running(),
             Content::Timer => self.timer.get_clock().is_running(),
             Content::Pomodoro => self.pomodoro.get_clock().is_running(),
+            Content::Counter => false,
+        }
+    }
+
+    fn just_finished(&mut self) -> bool {
+        match self.content {
+            Content::Countdown => self.countdown.just_finished(),
+            Content::Timer => false,
+            Content::Pomodoro => self.pomodoro.just_finished(),
+            Content::Counter => false,
+        }
+    }
+
+    fn just_reached_checkpoint(&mut self) -> bool {
+        match self.content {
+            Content::Countdown => self.countdown.just_reached_checkpoint(),
+            _ => false,
+        }
+    }
+
+    // Counts down ticks to flash the screen for and starts flashing
+    // if a clock is done or a checkpoint has been reached
+    fn update_flash(&mut self, event: &Event) {
+        if !self.flash {
+            return;
+        }
+        if let Event::Tick = event {
+            self.flash_ticks = self.flash_ticks.saturating_sub(1);
+        }
+        // both, to not keep any of them for later
+        let finished = self.just_finished();
+        if self.just_reached_checkpoint() || finished {
+            self.flash_ticks = FLASH_TICKS;
         }
     }
 
@@ -176,6 +315,7 @@ impl App {
             Content::Countdown => Some(self.countdown.get_clock().get_percentage_done()),
             Content::Timer => None,
             Content::Pomodoro => Some(self.pomodoro.get_clock().get_percentage_done()),
+            Content::Counter => None,
         }
     }
 
@@ -186,6 +326,7 @@ impl App {
             KeyCode::Char('c') => self.content = Content::Countdown,
             KeyCode::Char('t') => self.content = Content::Timer,
             KeyCode::Char('p') => self.content = Content::Pomodoro,
+            KeyCode::Char('n') => self.content = Content::Counter,
             KeyCode::Char('m') => self.show_menu = !self.show_menu,
             KeyCode::Char(',') => {
                 self.style = self.style.next();
@@ -193,6 +334,7 @@ impl App {
                 self.timer.set_style(self.style);
                 self.countdown.set_style(self.style);
                 self.pomodoro.set_style(self.style);
+                self.counter.set_style(self.style);
             }
             KeyCode::Char('.') => {
                 self.with_decis = !self.with_decis;
@@ -201,12 +343,39 @@ impl App {
                 self.countdown.set_with_decis(self.with_decis);
                 self.pomodoro.set_with_decis(self.with_decis);
             }
+            KeyCode::Char('R') => self.reset_to_defaults(),
             KeyCode::Up => self.show_menu = true,
             KeyCode::Down => self.show_menu = false,
             _ => {}
         };
     }
 
+    // Resets all clocks and restores style and deciseconds as configured at start
+    fn reset_to_defaults(&mut self) {
+        self.style = self.settings.style;
+        self.with_decis = self.settings.with_decis;
+        self.countdown.reset_to_defaults(&self.settings);
+        self.timer.reset_to_defaults(&self.settings);
+        self.pomodoro.reset_to_defaults(&self.settings);
+        self.counter.set_style(self.style);
+        self.counter.reset();
+    }
+
+    // Sets title of the terminal, but only if it has been changed
+    fn update_title(&mut self) -> Result<()> {
+        let title = match self.content {
+            Content::Countdown => terminal_title(self.countdown.get_clock()),
+            Content::Timer => terminal_title(self.timer.get_clock()),
+            Content::Pomodoro => terminal_title(self.pomodoro.get_clock()),
+            Content::Counter => format!("{} - {}", self.counter.get_count(), APP_NAME),
+        };
+        if title != self.title {
+            terminal::set_title(&title)?;
+            self.title = title;
+        }
+        Ok(())
+    }
+
     fn draw(&mut self, terminal: &mut Terminal) -> Result<()> {
         terminal.draw(|frame| {
             frame.render_stateful_widget(AppWidget, frame.area(), self);
@@ -216,9 +385,11 @@ impl App {
 
     pub fn to_storage(&self) -> AppStorage {
         AppStorage {
+            version: STORAGE_VERSION,
             content: self.content,
             show_menu: self.show_menu,
             style: self.style,
+            font: self.font,
             with_decis: self.with_decis,
             pomodoro_mode: self.pomodoro.get_mode().clone(),
             inital_value_work: Duration::from(*self.pomodoro.get_clock_work().get_initial_value()),
@@ -234,18 +405,66 @@ impl App {
                 *self.countdown.get_clock().get_current_value(),
             ),
             current_value_timer: Duration::from(*self.timer.get_clock().get_current_value()),
+            current_value_counter: self.counter.get_count(),
         }
     }
 }
 
+/// Title of the terminal to show current time of a clock,
+/// e.g. `⏳ 4:59 - timr`
+pub fn terminal_title<T: ClockKind>(clock: &Clock<T>) -> String {
+    match clock.get_mode() {
+        ClockMode::Tick => format!("⏳ {} - {}", clock.display_value(), APP_NAME),
+        ClockMode::Done => format!("⌛ done - {}", APP_NAME),
+        _ => format!("{} - {}", clock.display_value(), APP_NAME),
+    }
+}
+
+/// Fills the whole buffer with an inverted style of given color
+pub fn flash_frame(buf: &mut Buffer, color: Color) {
+    buf.set_style(
+        buf.area,
+        RatatuiStyle::default()
+            .fg(color)
+            .add_modifier(Modifier::REVERSED),
+    );
+}
+
 struct AppWidget;
 
 impl AppWidget {
     fn render_content(&self, area: Rect, buf: &mut Buffer, state: &mut App) {
         match state.content {
-            Content::Timer => TimerWidget.render(area, buf, &mut state.timer.clone()),
-            Content::Countdown => CountdownWidget.render(area, buf, &mut state.countdown.clone()),
-            Content::Pomodoro => PomodoroWidget.render(area, buf, &mut state.pomodoro.clone()),
+            Content::Timer => TimerWidget {
+                mono_digit: state.mono_digit,
+                emphasize_changes: state.emphasize_changes,
+                font: state.font,
+                scale: state.scale,
+                background_is_dark: state.background_is_dark,
+            }
+            .render(area, buf, &mut state.timer.clone()),
+            Content::Countdown => CountdownWidget {
+                mono_digit: state.mono_digit,
+                emphasize_changes: state.emphasize_changes,
+                font: state.font,
+                scale: state.scale,
+                background_is_dark: state.background_is_dark,
+            }
+            .render(area, buf, &mut state.countdown.clone()),
+            Content::Pomodoro => PomodoroWidget {
+                mono_digit: state.mono_digit,
+                emphasize_changes: state.emphasize_changes,
+                font: state.font,
+                scale: state.scale,
+                background_is_dark: state.background_is_dark,
+            }
+            .render(area, buf, &mut state.pomodoro.clone()),
+            Content::Counter => CounterWidget {
+                font: state.font,
+                scale: state.scale,
+                background_is_dark: state.background_is_dark,
+            }
+            .render(area, buf, &mut state.counter.clone()),
         };
     }
 }
@@ -263,6 +482,7 @@ impl StatefulWidget for AppWidget {
         // header
         Header {
             percentage: state.get_percentage_done(),
+            show_progress_percent: state.show_progress_percent,
         }
         .render(v0, buf);
         // content
@@ -275,5 +495,418 @@ impl StatefulWidget for AppWidget {
             edit_mode: state.is_edit_mode(),
         }
         .render(v2, buf);
+        // flash
+        if state.flash_ticks > 0 {
+            flash_frame(buf, Color::White);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::duration::{ONE_MINUTE, ONE_SECOND};
+
+    fn countdown(current_value: Duration) -> Clock<clock::Countdown> {
+        Clock::<clock::Countdown>::new(ClockArgs {
+            initial_value: ONE_MINUTE * 5,
+            current_value,
+            tick_value: ONE_SECOND,
+            style: Style::default(),
+            with_decis: false,
+        })
+    }
+
+    #[test]
+    fn test_terminal_title() {
+        let mut c = countdown(ONE_MINUTE * 5);
+        // initial
+        assert_eq!(terminal_title(&c), "5:00 - timr");
+        // running
+        c.toggle_pause();
+        c.tick();
+        assert_eq!(terminal_title(&c), "⏳ 4:59 - timr");
+        // paused
+        c.toggle_pause();
+        assert_eq!(terminal_title(&c), "4:59 - timr");
+    }
+
+    #[test]
+    fn test_terminal_title_done() {
+        let mut c = countdown(ONE_SECOND);
+        c.toggle_pause();
+        c.tick();
+        assert_eq!(terminal_title(&c), "⌛ done - timr");
+    }
+
+    #[test]
+    fn test_terminal_title_rendered() {
+        let mut c = Clock::<clock::Countdown>::new(ClockArgs {
+            initial_value: ONE_SECOND * 5,
+            current_value: ONE_SECOND * 5,
+            tick_value: Duration::from_millis(100),
+            style: Style::default(),
+            with_decis: false,
+        });
+        c.toggle_pause();
+        // 4.5s left
+        for _ in 0..5 {
+            c.tick();
+        }
+        let title = terminal_title(&c);
+        assert_eq!(title, "⏳ 5 - timr");
+        // same value as rendered by the widget
+        let value: Duration = title
+            .trim_start_matches("⏳ ")
+            .trim_end_matches(" - timr")
+            .parse::<crate::duration::DurationEx>()
+            .unwrap()
+            .into();
+        let mut expected = countdown(value);
+        let widget = clock::ClockWidget::new();
+        let width = widget.get_width(&c.get_display_format(), false);
+        let height = widget.get_height();
+        assert_eq!(
+            widget.render_to_string(&mut c, width, height),
+            clock::ClockWidget::new().render_to_string(&mut expected, width, height)
+        );
+    }
+
+    #[test]
+    fn test_countdown_widget_display_format() {
+        let render = |countdown: &mut Countdown| {
+            let area = Rect::new(0, 0, 40, 10);
+            let mut buf = Buffer::empty(area);
+            CountdownWidget {
+                mono_digit: false,
+                emphasize_changes: false,
+                font: DigitFont::default(),
+                scale: 1,
+                background_is_dark: true,
+            }
+            .render(area, &mut buf, countdown);
+            buf
+        };
+        let clock = |tick_value: Duration| {
+            let mut c = Clock::<clock::Countdown>::new(ClockArgs {
+                initial_value: ONE_MINUTE * 10,
+                current_value: ONE_MINUTE * 10,
+                tick_value,
+                style: Style::default(),
+                with_decis: false,
+            });
+            c.toggle_pause();
+            c
+        };
+        // `9:59.9` is displayed as `10:00`, which needs a wider layout than `9:59`
+        let mut ticked = clock(Duration::from_millis(100));
+        ticked.tick();
+        assert_eq!(ticked.get_format(), clock::Format::MSs);
+        assert_eq!(ticked.get_display_format(), clock::Format::MmSs);
+        assert_eq!(
+            render(&mut Countdown::new(ticked)),
+            render(&mut Countdown::new(clock(ONE_SECOND)))
+        );
+    }
+
+    #[test]
+    fn test_flash_frame() {
+        let mut b = Buffer::empty(Rect::new(0, 0, 4, 2));
+        flash_frame(&mut b, Color::White);
+        for x in 0..4 {
+            for y in 0..2 {
+                assert_eq!(b[(x, y)].fg, Color::White);
+                assert!(b[(x, y)].modifier.contains(Modifier::REVERSED));
+            }
+        }
+    }
+
+    #[test]
+    fn test_terminal_title_timer() {
+        let mut c = Clock::<clock::Timer>::new(ClockArgs {
+            initial_value: Duration::ZERO,
+            current_value: ONE_MINUTE * 61,
+            tick_value: ONE_SECOND,
+            style: Style::default(),
+            with_decis: false,
+        });
+        c.toggle_pause();
+        assert_eq!(terminal_title(&c), "⏳ 1:01:00 - timr");
+    }
+
+    fn app_args(args: &[&str]) -> AppArgs {
+        use clap::Parser;
+        let args = Args::try_parse_from([&["timr"], args].concat()).unwrap();
+        AppArgs::from((args, AppStorage::default()))
+    }
+
+    // ticks the active clock like `App::run` does
+    fn tick(app: &mut App) {
+        app.countdown.update(Event::Tick);
+        app.update_flash(&Event::Tick);
+    }
+
+    #[test]
+    fn test_update_flash() {
+        let mut app = App::new(app_args(&["1", "--flash"]));
+        app.countdown.update(Event::Key(KeyCode::Char('s').into()));
+        // 1s by a tick of 100ms
+        for _ in 0..10 {
+            tick(&mut app);
+        }
+        assert!(app.countdown.get_clock().is_done());
+        assert_eq!(app.flash_ticks, FLASH_TICKS);
+        // render does not change it
+        let mut b = Buffer::empty(Rect::new(0, 0, 40, 10));
+        AppWidget.render(b.area, &mut b, &mut app);
+        assert_eq!(app.flash_ticks, FLASH_TICKS);
+        for _ in 0..FLASH_TICKS {
+            tick(&mut app);
+        }
+        assert_eq!(app.flash_ticks, 0);
+    }
+
+    #[test]
+    fn test_update_flash_checkpoint() {
+        let mut app = App::new(app_args(&["2", "--flash", "--checkpoint", "1"]));
+        app.countdown.update(Event::Key(KeyCode::Char('s').into()));
+        for _ in 0..10 {
+            tick(&mut app);
+        }
+        assert!(!app.countdown.get_clock().is_done());
+        assert_eq!(app.flash_ticks, FLASH_TICKS);
+    }
+
+    #[test]
+    fn test_presets() {
+        let mut app = App::new(app_args(&["--preset", "1,2"]));
+        assert_eq!(app.content, Content::Countdown);
+        let initial = |app: &App| Duration::from(*app.countdown.get_clock().get_initial_value());
+        assert_eq!(initial(&app), ONE_SECOND);
+        app.countdown.update(Event::Key(KeyCode::Char('s').into()));
+        for _ in 0..10 {
+            tick(&mut app);
+        }
+        // next one is running
+        assert_eq!(initial(&app), ONE_SECOND * 2);
+        assert!(app.countdown.get_clock().is_running());
+    }
+
+    #[test]
+    fn test_repeat() {
+        let mut app = App::new(app_args(&["1", "--repeat"]));
+        app.countdown.update(Event::Key(KeyCode::Char('s').into()));
+        for _ in 0..15 {
+            tick(&mut app);
+        }
+        // started again
+        assert!(app.countdown.get_clock().is_running());
+        assert!(!app.countdown.just_finished());
+    }
+
+    #[test]
+    fn test_update_flash_disabled() {
+        let mut app = App::new(app_args(&["1"]));
+        app.countdown.update(Event::Key(KeyCode::Char('s').into()));
+        for _ in 0..10 {
+            tick(&mut app);
+        }
+        assert_eq!(app.flash_ticks, 0);
+        // finish is not consumed
+        assert!(app.countdown.just_finished());
+    }
+
+    #[test]
+    fn test_reset_to_defaults() {
+        let mut app = App::new(app_args(&["5:00", "--style", "cross"]));
+        let key = |c: char| Event::Key(KeyCode::Char(c).into());
+        // change style and deciseconds
+        app.handle_key_event(KeyCode::Char(',').into());
+        app.handle_key_event(KeyCode::Char('.').into());
+        assert_ne!(app.style, Style::Cross);
+        assert!(app.with_decis);
+        app.countdown.update(key('s'));
+        app.countdown.update(Event::Tick);
+        app.countdown.update(key('s'));
+        app.counter.update(key('+'));
+
+        app.handle_key_event(KeyCode::Char('R').into());
+        assert_eq!(app.style, Style::Cross);
+        assert!(!app.with_decis);
+        let clock = app.countdown.get_clock();
+        assert_eq!(clock.style, Style::Cross);
+        assert!(!clock.with_decis);
+        assert_eq!(clock.get_mode(), &ClockMode::Initial);
+        assert_eq!(Duration::from(*clock.get_current_value()), ONE_MINUTE * 5);
+        assert_eq!(app.timer.get_clock().style, Style::Cross);
+        assert_eq!(app.pomodoro.get_clock().style, Style::Cross);
+        assert_eq!(app.counter.get_count(), 0);
+    }
+
+    #[test]
+    fn test_delta_keys() {
+        let mut app = App::new(app_args(&["5:00"]));
+        let key = |c: char| Event::Key(KeyCode::Char(c).into());
+        let current = |app: &App| Duration::from(*app.countdown.get_clock().get_current_value());
+        assert!(app.countdown.update(key('+')).is_none());
+        assert_eq!(current(&app), ONE_MINUTE * 6);
+        app.countdown.update(key('-'));
+        app.countdown.update(key('-'));
+        assert_eq!(current(&app), ONE_MINUTE * 4);
+        // timer
+        app.timer.update(key('+'));
+        assert_eq!(
+            Duration::from(*app.timer.get_clock().get_current_value()),
+            ONE_MINUTE
+        );
+        // not while editing
+        app.countdown.update(key('e'));
+        assert!(app.countdown.update(key('+')).is_some());
+        assert_eq!(current(&app), ONE_MINUTE * 4);
+    }
+
+    #[test]
+    fn test_rewind_key() {
+        let mut app = App::new(app_args(&["5:00"]));
+        let key = |c: char| Event::Key(KeyCode::Char(c).into());
+        app.countdown.update(key('s'));
+        for _ in 0..150 {
+            app.countdown.update(Event::Tick);
+        }
+        assert!(app.countdown.update(key('b')).is_none());
+        assert_eq!(
+            Duration::from(*app.countdown.get_clock().get_current_value()),
+            ONE_MINUTE * 5 - ONE_SECOND * 5
+        );
+        // still running
+        assert!(app.countdown.get_clock().is_running());
+        // timer
+        app.timer.update(key('+'));
+        app.timer.update(key('b'));
+        assert_eq!(
+            Duration::from(*app.timer.get_clock().get_current_value()),
+            ONE_SECOND * 50
+        );
+    }
+
+    #[test]
+    fn test_snooze_key() {
+        let mut app = App::new(app_args(&["1"]));
+        let key = |c: char| Event::Key(KeyCode::Char(c).into());
+        // not done -> ignored
+        assert!(app.countdown.update(key('z')).is_none());
+        assert_eq!(
+            Duration::from(*app.countdown.get_clock().get_current_value()),
+            ONE_SECOND
+        );
+        app.countdown.update(key('s'));
+        for _ in 0..10 {
+            tick(&mut app);
+        }
+        assert!(app.countdown.get_clock().is_done());
+        app.countdown.update(key('z'));
+        assert!(app.countdown.get_clock().is_running());
+        assert_eq!(
+            Duration::from(*app.countdown.get_clock().get_current_value()),
+            ONE_MINUTE * 5
+        );
+    }
+
+    #[test]
+    fn test_paste() {
+        let mut app = App::new(app_args(&[]));
+        let paste = |s: &str| Event::Paste(s.to_string());
+        assert!(app.countdown.update(paste(" 1h30m\n")).is_none());
+        assert_eq!(
+            Duration::from(*app.countdown.get_clock().get_current_value()),
+            ONE_MINUTE * 90
+        );
+        // invalid text leaves the clock untouched
+        app.countdown.update(paste("foo"));
+        assert_eq!(
+            Duration::from(*app.countdown.get_clock().get_current_value()),
+            ONE_MINUTE * 90
+        );
+        app.timer.update(paste("1:30"));
+        assert_eq!(
+            Duration::from(*app.timer.get_clock().get_current_value()),
+            ONE_SECOND * 90
+        );
+    }
+
+    #[test]
+    fn test_lap_key() {
+        let mut app = App::new(app_args(&[]));
+        let key = |c: char| Event::Key(KeyCode::Char(c).into());
+        app.timer.update(key('s'));
+        for _ in 0..30 {
+            app.timer.update(Event::Tick);
+        }
+        assert!(app.timer.update(key('l')).is_none());
+        for _ in 0..20 {
+            app.timer.update(Event::Tick);
+        }
+        app.timer.update(key('l'));
+        // still running
+        assert!(app.timer.get_clock().is_running());
+        assert_eq!(app.timer.get_clock().laps().len(), 2);
+        assert_eq!(
+            app.timer.get_clock().last_split().map(Duration::from),
+            Some(ONE_SECOND * 2)
+        );
+        // ignored while editing
+        app.timer.update(key('e'));
+        assert!(app.timer.update(key('l')).is_some());
+        assert_eq!(app.timer.get_clock().laps().len(), 2);
+    }
+
+    #[test]
+    fn test_fixed_format() {
+        let app = App::new(app_args(&["5", "--fixed-format", "hh:mm:ss"]));
+        assert_eq!(app.countdown.get_clock().get_format(), Format::HhMmSs);
+        assert_eq!(app.timer.get_clock().get_format(), Format::HhMmSs);
+        assert_eq!(app.pomodoro.get_clock_work().get_format(), Format::HhMmSs);
+        assert_eq!(app.pomodoro.get_clock_pause().get_format(), Format::HhMmSs);
+        // kept by restoring defaults
+        let mut app = app;
+        app.handle_key_event(KeyCode::Char('R').into());
+        assert_eq!(app.timer.get_clock().get_format(), Format::HhMmSs);
+        // switched by value by default
+        let app = App::new(app_args(&["5"]));
+        assert_eq!(app.countdown.get_clock().get_format(), Format::S);
+    }
+
+    #[test]
+    fn test_emphasize_changes() {
+        let app = App::new(app_args(&["5", "--emphasize-changes"]));
+        assert!(app.emphasize_changes);
+        let app = App::new(app_args(&["5"]));
+        assert!(!app.emphasize_changes);
+    }
+
+    #[test]
+    fn test_app_args_duration() {
+        let a = app_args(&["90"]);
+        assert_eq!(a.content, Content::Countdown);
+        assert_eq!(a.initial_value_countdown, ONE_SECOND * 90);
+        assert_eq!(a.current_value_countdown, ONE_SECOND * 90);
+        // explicit mode wins
+        let a = app_args(&["1:30:00", "--mode", "timer"]);
+        assert_eq!(a.content, Content::Timer);
+        assert_eq!(a.initial_value_countdown, ONE_MINUTE * 90);
+        // read from stdin (see `main`)
+        let mut args = <Args as clap::Parser>::try_parse_from(["timr", "--stdin"]).unwrap();
+        args.duration = Some(crate::args::read_duration("2:30\n".as_bytes()).unwrap());
+        let a = AppArgs::from((args, AppStorage::default()));
+        assert_eq!(a.content, Content::Countdown);
+        assert_eq!(a.initial_value_countdown, ONE_SECOND * 150);
+        // stored values w/o duration
+        let a = app_args(&[]);
+        assert_eq!(a.content, AppStorage::default().content);
+        assert_eq!(
+            a.initial_value_countdown,
+            AppStorage::default().inital_value_countdown
+        );
     }
 }
@@ -1,26 +1,35 @@
 use clap::Parser;
 use color_eyre::{
-    eyre::{ensure, eyre},
+    eyre::{ensure, WrapErr},
     Report,
 };
-use std::time::Duration;
+use std::{io::BufRead, time::Duration};
 
-use crate::common::{Content, Style};
+use crate::{
+    common::{Content, DigitFont, Style},
+    duration::DurationEx,
+    widgets::clock::Format,
+};
 
 #[derive(Parser)]
 pub struct Args {
+    #[arg(value_parser = parse_duration, conflicts_with = "countdown",
+        help = "Countdown time to start with (same as `--countdown` incl. `--mode countdown`). Formats: 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m'"
+    )]
+    pub duration: Option<Duration>,
+
     #[arg(long, short, value_parser = parse_duration,
-        help = "Countdown time to start from. Formats: 'ss', 'mm:ss', or 'hh:mm:ss'"
+        help = "Countdown time to start from. Formats: 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m'"
     )]
     pub countdown: Option<Duration>,
 
     #[arg(long, short, value_parser = parse_duration,
-        help = "Work time to count down from. Formats: 'ss', 'mm:ss', or 'hh:mm:ss'"
+        help = "Work time to count down from. Formats: 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m'"
     )]
     pub work: Option<Duration>,
 
     #[arg(long, short, value_parser = parse_duration,
-        help = "Pause time to count down from. Formats: 'ss', 'mm:ss', or 'hh:mm:ss'"
+        help = "Pause time to count down from. Formats: 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m'"
     )]
     pub pause: Option<Duration>,
 
@@ -33,48 +42,75 @@ pub struct Args {
     #[arg(long, short = 's', value_enum, help = "Style to display time with.")]
     pub style: Option<Style>,
 
+    #[arg(long, value_enum, help = "Font to display digits with.")]
+    pub font: Option<DigitFont>,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Format to display time with instead of switching it by value, e.g. `hh:mm:ss` for `00:00:05`."
+    )]
+    pub fixed_format: Option<Format>,
+
+    #[arg(long = "preset", value_parser = parse_duration, value_delimiter = ',',
+        help = "Countdown times to run one after another, e.g. `--preset 25:00,5:00`. Formats: 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m'"
+    )]
+    pub presets: Vec<Duration>,
+
+    #[arg(long = "checkpoint", value_parser = parse_duration, value_delimiter = ',',
+        help = "Remaining times of a countdown to flash the screen at (see `--flash`), e.g. `--checkpoint 1:00,10`. Formats: 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m'"
+    )]
+    pub checkpoints: Vec<Duration>,
+
+    #[arg(
+        long,
+        conflicts_with = "presets",
+        help = "Start a countdown again when it's done."
+    )]
+    pub repeat: bool,
+
+    #[arg(long, conflicts_with_all = ["countdown", "duration"],
+        help = "Read countdown time to start with from stdin, e.g. `echo 5:00 | timr --stdin`."
+    )]
+    pub stdin: bool,
+
     #[arg(long, short = 'r', help = "Reset stored values to default.")]
     pub reset: bool,
+
+    #[arg(long, help = "Show current time in title of the terminal.")]
+    pub title: bool,
+
+    #[arg(long, help = "Flash the screen if a countdown is done.")]
+    pub flash: bool,
+
+    #[arg(long, help = "Show percentage of progress as text.")]
+    pub percent: bool,
+
+    #[arg(long, help = "Show time as normal text with big colons only.")]
+    pub mono_digit: bool,
+
+    #[arg(long, help = "Show digits changed by last tick in bold.")]
+    pub emphasize_changes: bool,
+
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..=4),
+        help = "Number of terminal rows to draw each row of digits with."
+    )]
+    pub scale: u16,
 }
 
 fn parse_duration(arg: &str) -> Result<Duration, Report> {
-    let parts: Vec<&str> = arg.split(':').rev().collect();
-
-    let parse_seconds = |s: &str| -> Result<u64, Report> {
-        let secs = s.parse::<u64>().map_err(|_| eyre!("Invalid seconds"))?;
-        ensure!(secs < 60, "Seconds must be less than 60.");
-        Ok(secs)
-    };
-
-    let parse_minutes = |m: &str| -> Result<u64, Report> {
-        let mins = m.parse::<u64>().map_err(|_| eyre!("Invalid minutes"))?;
-        ensure!(mins < 60, "Minutes must be less than 60.");
-        Ok(mins)
-    };
-
-    let parse_hours = |h: &str| -> Result<u64, Report> {
-        let hours = h.parse::<u64>().map_err(|_| eyre!("Invalid hours"))?;
-        ensure!(hours < 100, "Hours must be less than 100.");
-        Ok(hours)
-    };
-
-    let seconds = match parts.as_slice() {
-        [ss] => parse_seconds(ss)?,
-        [ss, mm] => {
-            let s = parse_seconds(ss)?;
-            let m = parse_minutes(mm)?;
-            m * 60 + s
-        }
-        [ss, mm, hh] => {
-            let s = parse_seconds(ss)?;
-            let m = parse_minutes(mm)?;
-            let h = parse_hours(hh)?;
-            h * 60 * 60 + m * 60 + s
-        }
-        _ => return Err(eyre!("Invalid time format. Use 'ss', mm:ss, or hh:mm:ss")),
-    };
-
-    Ok(Duration::from_secs(seconds))
+    arg.parse::<DurationEx>().map(Duration::from)
+}
+
+/// Reads a time (e.g. `5:00`) from the first line of `reader` (e.g. stdin)
+pub fn read_duration(mut reader: impl BufRead) -> Result<Duration, Report> {
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .wrap_err("Failed to read time from stdin")?;
+    let line = line.trim();
+    ensure!(!line.is_empty(), "No time given by stdin");
+    parse_duration(line).wrap_err_with(|| format!("Invalid time given by stdin: '{}'", line))
 }
 
 #[cfg(test)]
@@ -101,5 +137,114 @@ mod tests {
         assert!(parse_duration("100:00:00").is_err()); // invalid hours
         assert!(parse_duration("abc").is_err()); // invalid input
         assert!(parse_duration("01:02:03:04").is_err()); // too many parts
+
+        // units
+        assert_eq!(
+            parse_duration("1h30m").unwrap(),
+            Duration::from_secs(60 * 60 + 30 * 60)
+        );
+        assert!(parse_duration("100h").is_err()); // too much
+    }
+
+    #[test]
+    fn test_font() {
+        let font = |arg: &str| Args::try_parse_from(["timr", "--font", arg]).map(|a| a.font);
+        assert_eq!(font("block").unwrap(), Some(DigitFont::Block));
+        assert_eq!(font("half-height").unwrap(), Some(DigitFont::HalfHeight));
+        assert!(font("unknown").is_err());
+        // none by default to use stored font
+        assert_eq!(Args::try_parse_from(["timr"]).unwrap().font, None);
+    }
+
+    #[test]
+    fn test_duration() {
+        let duration = |arg: &str| Args::try_parse_from(["timr", arg]).map(|a| a.duration);
+        assert_eq!(
+            duration("1:30:00").unwrap(),
+            Some(Duration::from_secs(60 * 60 + 30 * 60))
+        );
+        // seconds only
+        assert_eq!(duration("90").unwrap(), Some(Duration::from_secs(90)));
+        assert!(duration("abc").is_err());
+        assert_eq!(Args::try_parse_from(["timr"]).unwrap().duration, None);
+        // `--countdown` is the same
+        assert!(Args::try_parse_from(["timr", "5:00", "--countdown", "10:00"]).is_err());
+    }
+
+    #[test]
+    fn test_read_duration() {
+        assert_eq!(
+            read_duration("5:00\n".as_bytes()).unwrap(),
+            Duration::from_secs(5 * 60)
+        );
+        // first line only
+        assert_eq!(
+            read_duration(" 90 \n10:00\n".as_bytes()).unwrap(),
+            Duration::from_secs(90)
+        );
+        assert!(read_duration("".as_bytes()).is_err());
+        assert!(read_duration("\n".as_bytes()).is_err());
+        assert!(read_duration("abc\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_fixed_format() {
+        let format = |arg: &str| {
+            Args::try_parse_from(["timr", "--fixed-format", arg]).map(|a| a.fixed_format)
+        };
+        assert_eq!(format("hh:mm:ss").unwrap(), Some(Format::HhMmSs));
+        assert_eq!(format("m:ss").unwrap(), Some(Format::MSs));
+        assert_eq!(format("s").unwrap(), Some(Format::S));
+        assert!(format("hh:mm").is_err());
+        // switching by value by default
+        assert_eq!(Args::try_parse_from(["timr"]).unwrap().fixed_format, None);
+    }
+
+    #[test]
+    fn test_presets_checkpoints() {
+        let args =
+            Args::try_parse_from(["timr", "--preset", "25:00,5:00", "--preset", "1m"]).unwrap();
+        assert_eq!(
+            args.presets,
+            vec![
+                Duration::from_secs(25 * 60),
+                Duration::from_secs(5 * 60),
+                Duration::from_secs(60)
+            ]
+        );
+        let args = Args::try_parse_from(["timr", "--checkpoint", "1:00,10"]).unwrap();
+        assert_eq!(
+            args.checkpoints,
+            vec![Duration::from_secs(60), Duration::from_secs(10)]
+        );
+        assert!(Args::try_parse_from(["timr", "--preset", "abc"]).is_err());
+        // none by default
+        let args = Args::try_parse_from(["timr"]).unwrap();
+        assert!(args.presets.is_empty());
+        assert!(args.checkpoints.is_empty());
+    }
+
+    #[test]
+    fn test_repeat() {
+        assert!(Args::try_parse_from(["timr", "--repeat"]).unwrap().repeat);
+        assert!(!Args::try_parse_from(["timr"]).unwrap().repeat);
+        // presets run one after another instead
+        assert!(Args::try_parse_from(["timr", "--repeat", "--preset", "1,2"]).is_err());
+    }
+
+    #[test]
+    fn test_scale() {
+        let scale = |arg: &str| Args::try_parse_from(["timr", "--scale", arg]).map(|a| a.scale);
+        assert_eq!(scale("2").unwrap(), 2);
+        assert!(scale("0").is_err());
+        assert!(scale("5").is_err());
+        assert_eq!(Args::try_parse_from(["timr"]).unwrap().scale, 1);
+    }
+
+    #[test]
+    fn test_stdin() {
+        assert!(Args::try_parse_from(["timr", "--stdin"]).unwrap().stdin);
+        assert!(Args::try_parse_from(["timr", "--stdin", "5:00"]).is_err());
+        assert!(Args::try_parse_from(["timr", "--stdin", "-c", "5:00"]).is_err());
     }
 }
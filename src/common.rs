@@ -13,9 +13,11 @@ pub enum Content {
     Timer,
     #[value(name = "pomodoro", alias = "p")]
     Pomodoro,
+    #[value(name = "counter", alias = "n")]
+    Counter,
 }
 
-#[derive(Debug, Copy, Clone, ValueEnum, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize)]
 pub enum Style {
     #[default]
     #[value(name = "full", alias = "f")]
@@ -30,11 +32,35 @@ pub enum Style {
     Thick,
     #[value(name = "cross", alias = "c")]
     Cross,
+    /// Shades from light (top) to full (bottom)
+    #[value(name = "gradient", alias = "g")]
+    Gradient,
     /// https://en.wikipedia.org/wiki/Braille_Patterns
     /// Note: Might not be supported in all terminals
     /// see https://docs.rs/ratatui/latest/src/ratatui/symbols.rs.html#150
     #[value(name = "braille", alias = "b")]
     Braille,
+    /// Compact digits drawn by braille dots (2x4 dots per cell)
+    /// Note: Might not be supported in all terminals (see `Braille`)
+    #[value(name = "dots", alias = "o")]
+    Dots,
+    /// Shade with high contrast to the background of the terminal
+    #[value(name = "auto", alias = "a")]
+    Auto,
+}
+
+/// Font (bitmap) to draw digits of a clock with.
+/// Symbols to draw a font are still picked by `Style`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DigitFont {
+    /// Digits of 5x5 cells
+    #[default]
+    #[value(name = "block")]
+    Block,
+    /// Digits of 5x3 cells drawn by half blocks (two rows of a digit per cell)
+    #[value(name = "half-height")]
+    HalfHeight,
 }
 
 impl Style {
@@ -44,21 +70,77 @@ impl Style {
             Style::Dark => Style::Medium,
             Style::Medium => Style::Light,
             Style::Light => Style::Braille,
-            Style::Braille => Style::Thick,
+            Style::Braille => Style::Dots,
+            Style::Dots => Style::Thick,
             Style::Thick => Style::Cross,
-            Style::Cross => Style::Full,
+            Style::Cross => Style::Gradient,
+            Style::Gradient => Style::Auto,
+            Style::Auto => Style::Full,
         }
     }
 
-    pub fn get_digit_symbol(&self) -> &str {
+    /// Symbol to render digits with on a dark or light background.
+    /// `Dark` (high contrast) and `Light` (low contrast) are relative to the background,
+    /// that's why their shades are swapped on light backgrounds.
+    pub fn get_digit_symbol_on(&self, background_is_dark: bool) -> &str {
         match &self {
-            Style::Full => shade::FULL,
-            Style::Light => shade::LIGHT,
+            // fallback only (e.g. colons), digits of `Gradient` are rendered by `Digit::gradient`
+            Style::Full | Style::Gradient => shade::FULL,
             Style::Medium => shade::MEDIUM,
-            Style::Dark => shade::DARK,
+            Style::Dark | Style::Auto if background_is_dark => shade::DARK,
+            Style::Dark | Style::Auto => shade::LIGHT,
+            Style::Light if background_is_dark => shade::LIGHT,
+            Style::Light => shade::DARK,
             Style::Cross => "╬",
             Style::Thick => "┃",
-            Style::Braille => "⣿",
+            // fallback only, `Dots` are rendered by `Digit::braille`
+            Style::Braille | Style::Dots => "⣿",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_digit_symbol_dark_background() {
+        assert_eq!(Style::Dark.get_digit_symbol_on(true), shade::DARK);
+        assert_eq!(Style::Light.get_digit_symbol_on(true), shade::LIGHT);
+        assert_eq!(Style::Auto.get_digit_symbol_on(true), shade::DARK);
+        assert_eq!(Style::Full.get_digit_symbol_on(true), shade::FULL);
+        assert_eq!(Style::Medium.get_digit_symbol_on(true), shade::MEDIUM);
+    }
+
+    #[test]
+    fn test_digit_symbol_light_background() {
+        assert_eq!(Style::Dark.get_digit_symbol_on(false), shade::LIGHT);
+        assert_eq!(Style::Light.get_digit_symbol_on(false), shade::DARK);
+        assert_eq!(Style::Auto.get_digit_symbol_on(false), shade::LIGHT);
+        assert_eq!(Style::Full.get_digit_symbol_on(false), shade::FULL);
+        assert_eq!(Style::Medium.get_digit_symbol_on(false), shade::MEDIUM);
+    }
+
+    #[test]
+    fn test_digit_font_value_enum() {
+        assert_eq!(DigitFont::from_str("block", false), Ok(DigitFont::Block));
+        assert_eq!(
+            DigitFont::from_str("half-height", false),
+            Ok(DigitFont::HalfHeight)
+        );
+        // braille digits are drawn by `Style::Dots`
+        assert!(DigitFont::from_str("braille-subcell", false).is_err());
+    }
+
+    #[test]
+    fn test_digit_font_serde() {
+        for font in DigitFont::value_variants() {
+            let json = serde_json::to_string(font).unwrap();
+            let name = font.to_possible_value().unwrap();
+            // same names as used by CLI
+            assert_eq!(json, format!("\"{}\"", name.get_name()));
+            assert_eq!(serde_json::from_str::<DigitFont>(&json).unwrap(), *font);
         }
     }
 }
@@ -1,8 +1,13 @@
-use crate::constants::APP_NAME;
+use crate::{common::Style, constants::APP_NAME, widgets::clock::Format};
 use color_eyre::eyre::{eyre, Result};
 use directories::ProjectDirs;
-use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs};
+
+/// Env. variable to override the directory to store logs and data in
+pub const STATE_DIR_ENV: &str = "TIMR_STATE_DIR";
+
 pub struct Config {
     pub log_dir: PathBuf,
     pub data_dir: PathBuf,
@@ -10,15 +15,52 @@ pub struct Config {
 
 impl Config {
     pub fn init() -> Result<Self> {
-        let log_dir = get_default_state_dir()?.join("logs");
+        let state_dir = state_dir_from(env::var_os(STATE_DIR_ENV).map(PathBuf::from))?;
+        Self::init_in(state_dir)
+    }
+
+    // Creates (missing) directories of logs and data in `state_dir`
+    fn init_in(state_dir: PathBuf) -> Result<Self> {
+        let log_dir = state_dir.join("logs");
         fs::create_dir_all(&log_dir)?;
-        let data_dir = get_default_state_dir()?.join("data");
+        let data_dir = state_dir.join("data");
         fs::create_dir_all(&data_dir)?;
 
         Ok(Self { log_dir, data_dir })
     }
 }
 
+/// Configured defaults a clock can be (re-)initialized with
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub style: Style,
+    pub with_decis: bool,
+    /// Format of all clocks instead of switching it by value (see `Clock::set_fixed_format`)
+    pub fixed_format: Option<Format>,
+    /// Time of a new countdown
+    pub countdown: Duration,
+    /// Remaining times of a countdown to announce (see `Clock::set_checkpoints`)
+    pub checkpoints: Vec<Duration>,
+    /// Times of a countdown to run one after another (see `Clock::set_presets`)
+    pub presets: Vec<Duration>,
+    /// Whether a countdown (w/o presets) starts again when it's done (see `FinishAction::Repeat`)
+    pub repeat: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            with_decis: false,
+            fixed_format: None,
+            countdown: Duration::from_secs(60 * 10), /* 10min */
+            checkpoints: Vec::new(),
+            presets: Vec::new(),
+            repeat: false,
+        }
+    }
+}
+
 pub fn get_project_dir() -> Result<ProjectDirs> {
     let dirs = ProjectDirs::from("", "", APP_NAME)
         .ok_or_else(|| eyre!("Failed to get project directories"))?;
@@ -26,8 +68,15 @@ pub fn get_project_dir() -> Result<ProjectDirs> {
     Ok(dirs)
 }
 
+// Directory of `STATE_DIR_ENV` (if set) wins over the default directory
+fn state_dir_from(env: Option<PathBuf>) -> Result<PathBuf> {
+    match env {
+        Some(dir) if !dir.as_os_str().is_empty() => Ok(dir),
+        _ => get_default_state_dir(),
+    }
+}
+
 fn get_default_state_dir() -> Result<PathBuf> {
-    println!("{:?}",get_project_dir());
     let directory = get_project_dir()?
         .state_dir()
         .map(|d| d.to_path_buf())
@@ -38,3 +87,32 @@ fn get_default_state_dir() -> Result<PathBuf> {
 
     Ok(directory)
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_state_dir_from() {
+        let dir = PathBuf::from("/tmp/timr");
+        assert_eq!(state_dir_from(Some(dir.clone())).unwrap(), dir);
+        let default = get_default_state_dir().unwrap();
+        assert_eq!(state_dir_from(None).unwrap(), default);
+        // empty value is ignored
+        assert_eq!(state_dir_from(Some(PathBuf::new())).unwrap(), default);
+    }
+
+    #[test]
+    fn test_init_in() {
+        let dir = env::temp_dir().join(format!("{}-test-{}", APP_NAME, std::process::id()));
+        let config = Config::init_in(dir.clone()).unwrap();
+
+        assert_eq!(config.log_dir, dir.join("logs"));
+        assert_eq!(config.data_dir, dir.join("data"));
+        // created if missing
+        assert!(config.log_dir.is_dir());
+        assert!(config.data_dir.is_dir());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
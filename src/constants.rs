@@ -3,3 +3,6 @@ pub static APP_NAME: &str = env!("CARGO_PKG_NAME");
 // TODO: Grab those values from `Args`
 pub static TICK_VALUE_MS: u64 = 1000 / 10; // 0.1 sec in milliseconds
 pub static FPS_VALUE_MS: u64 = 1000 / 60; // 60 FPS in milliseconds
+pub static DELTA_SECS: i64 = 60; // seconds to add or subtract by `+` / `-`
+pub static REWIND_VALUE_MS: u64 = 10 * 1000; // 10 sec. to step back by `b`
+pub static SNOOZE_VALUE_MS: u64 = 5 * 60 * 1000; // 5 min. to snooze a done countdown by `z`
@@ -1,4 +1,10 @@
+use color_eyre::{
+    eyre::{ensure, eyre},
+    Report,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::str::FromStr;
 use std::time::Duration;
 
 pub const ONE_DECI_SECOND: Duration = Duration::from_millis(100);
@@ -6,6 +12,10 @@ pub const ONE_SECOND: Duration = Duration::from_secs(1);
 pub const ONE_MINUTE: Duration = Duration::from_secs(SECS_PER_MINUTE);
 pub const ONE_HOUR: Duration = Duration::from_secs(MINS_PER_HOUR * SECS_PER_MINUTE);
 
+// max. 99:59:59
+pub const MAX_DURATION: Duration =
+    Duration::from_secs(100 * MINS_PER_HOUR * SECS_PER_MINUTE).saturating_sub(ONE_SECOND);
+
 // unstable
 // https://doc.rust-lang.org/src/core/time.rs.html#32
 pub const SECS_PER_MINUTE: u64 = 60;
@@ -84,13 +94,149 @@ impl DurationEx {
     }
 }
 
+/// Parses `ss`, `mm:ss` or `hh:mm:ss` (e.g. `"25:00"`) or units like `1h30m15s`
+/// (each of `h`, `m` and `s` is optional, but in that order).
+/// Seconds only (`ss`) and units can be any number (e.g. `"90"` for `1:30` or `"90m"`),
+/// as long as the time does not exceed `MAX_DURATION`.
+impl FromStr for DurationEx {
+    type Err = Report;
+
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        let arg = arg.trim();
+        if arg.contains(['h', 'm', 's']) {
+            let seconds = parse_units(arg)?;
+            ensure!(
+                seconds <= MAX_DURATION.as_secs(),
+                "Time must not be more than {}.",
+                DurationEx::from(MAX_DURATION)
+            );
+            return Ok(Duration::from_secs(seconds).into());
+        }
+
+        let parts: Vec<&str> = arg.split(':').rev().collect();
+
+        let parse_seconds = |s: &str| -> Result<u64, Report> {
+            let secs = s.parse::<u64>().map_err(|_| eyre!("Invalid seconds"))?;
+            ensure!(secs < 60, "Seconds must be less than 60.");
+            Ok(secs)
+        };
+
+        let parse_minutes = |m: &str| -> Result<u64, Report> {
+            let mins = m.parse::<u64>().map_err(|_| eyre!("Invalid minutes"))?;
+            ensure!(mins < 60, "Minutes must be less than 60.");
+            Ok(mins)
+        };
+
+        let parse_hours = |h: &str| -> Result<u64, Report> {
+            let hours = h.parse::<u64>().map_err(|_| eyre!("Invalid hours"))?;
+            ensure!(hours < 100, "Hours must be less than 100.");
+            Ok(hours)
+        };
+
+        let seconds = match parts.as_slice() {
+            [ss] => {
+                let secs = ss.parse::<u64>().map_err(|_| eyre!("Invalid seconds"))?;
+                ensure!(
+                    secs <= MAX_DURATION.as_secs(),
+                    "Seconds must not be more than {}.",
+                    MAX_DURATION.as_secs()
+                );
+                secs
+            }
+            [ss, mm] => {
+                let s = parse_seconds(ss)?;
+                let m = parse_minutes(mm)?;
+                m * 60 + s
+            }
+            [ss, mm, hh] => {
+                let s = parse_seconds(ss)?;
+                let m = parse_minutes(mm)?;
+                let h = parse_hours(hh)?;
+                h * 60 * 60 + m * 60 + s
+            }
+            _ => {
+                return Err(eyre!(
+                    "Invalid time format. Use 'ss', 'mm:ss', 'hh:mm:ss' or units like '1h30m15s'"
+                ))
+            }
+        };
+
+        Ok(Duration::from_secs(seconds).into())
+    }
+}
+
+// Seconds of `1h30m15s`, `90m`, `2h5s` etc.
+fn parse_units(s: &str) -> Result<u64, Report> {
+    const UNITS: [(char, u64); 3] = [
+        ('h', MINS_PER_HOUR * SECS_PER_MINUTE),
+        ('m', SECS_PER_MINUTE),
+        ('s', 1),
+    ];
+    let mut rest = s;
+    let mut secs: u64 = 0;
+    for (unit, factor) in UNITS {
+        if let Some((value, tail)) = rest.split_once(unit) {
+            ensure!(
+                !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()),
+                "Invalid value for '{}' in '{}'",
+                unit,
+                s
+            );
+            let value = value.parse::<u64>().unwrap_or(u64::MAX);
+            secs = secs.saturating_add(value.saturating_mul(factor));
+            rest = tail;
+        }
+    }
+    ensure!(
+        rest.is_empty(),
+        "Invalid time format '{}'. Use units in order of 'h', 'm' and 's', e.g. 1h30m15s",
+        s
+    );
+    Ok(secs)
+}
+
+/// Stable (and human-editable) schema to (de-)serialize `DurationEx`,
+/// e.g. `{ "secs": 90, "decis": 5 }`
+#[derive(Serialize, Deserialize)]
+struct DurationExSchema {
+    secs: u64,
+    decis: u8,
+}
+
+impl Serialize for DurationEx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DurationExSchema {
+            secs: self.seconds(),
+            decis: self.decis() as u8,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationEx {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let DurationExSchema { secs, decis } = DurationExSchema::deserialize(deserializer)?;
+        if decis > 9 {
+            return Err(de::Error::custom("decis must be less than 10"));
+        }
+        let inner = Duration::from_secs(secs) + ONE_DECI_SECOND * decis as u32;
+        Ok(Self { inner })
+    }
+}
+
 impl fmt::Display for DurationEx {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.hours() >= 10 {
             write!(
                 f,
                 "{:02}:{:02}:{:02}",
-                self.hours_mod(),
+                self.hours(),
                 self.minutes_mod(),
                 self.seconds_mod(),
             )
@@ -125,6 +271,9 @@ mod tests {
         // hh:mm:ss
         let ex: DurationEx = Duration::from_secs(36001).into();
         assert_eq!(format!("{}", ex), "10:00:01");
+        // hours above a day are not wrapped
+        let ex: DurationEx = Duration::from_secs(99 * 3600 + 59 * 60 + 59).into();
+        assert_eq!(format!("{}", ex), "99:59:59");
         // h:mm:ss
         let ex: DurationEx = Duration::from_secs(3601).into();
         assert_eq!(format!("{}", ex), "1:00:01");
@@ -157,4 +306,65 @@ mod tests {
         let ex3 = ex.saturating_add(ex2);
         assert_eq!(format!("{}", ex3), "11");
     }
+
+    #[test]
+    fn test_from_str() {
+        let ex: DurationEx = "25:00".parse().unwrap();
+        assert_eq!(Duration::from(ex), Duration::from_secs(25 * 60));
+        let ex: DurationEx = "1:02:03".parse().unwrap();
+        assert_eq!(format!("{}", ex), "1:02:03");
+        assert!("".parse::<DurationEx>().is_err());
+        assert!("1:60".parse::<DurationEx>().is_err());
+        // seconds only
+        let ex: DurationEx = "90".parse().unwrap();
+        assert_eq!(Duration::from(ex), Duration::from_secs(90));
+        assert!("360000".parse::<DurationEx>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_units() {
+        let parse = |s: &str| s.parse::<DurationEx>().map(Duration::from);
+        assert_eq!(
+            parse("1h30m15s").unwrap(),
+            ONE_HOUR + ONE_MINUTE * 30 + ONE_SECOND * 15
+        );
+        assert_eq!(parse("2h").unwrap(), ONE_HOUR * 2);
+        assert_eq!(parse("90m").unwrap(), ONE_MINUTE * 90);
+        assert_eq!(parse("1h5s").unwrap(), ONE_HOUR + ONE_SECOND * 5);
+        // surrounding whitespace
+        assert_eq!(parse(" 5m\n").unwrap(), ONE_MINUTE * 5);
+        // max.
+        assert_eq!(parse("99h59m59s").unwrap(), MAX_DURATION);
+        assert!(parse("100h").is_err());
+        for s in ["h", "1m2h", "1h1h", "1x", "1h30", "1.5h", "-5m", "1h:30m"] {
+            assert!(parse(s).is_err(), "{:?} should be invalid", s);
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        let ex: DurationEx = Duration::from_millis(90_500).into();
+        assert_eq!(
+            serde_json::to_string(&ex).unwrap(),
+            r#"{"secs":90,"decis":5}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let ex: DurationEx = serde_json::from_str(r#"{ "secs": 90, "decis": 5 }"#).unwrap();
+        assert_eq!(Duration::from(ex), Duration::from_millis(90_500));
+        // invalid decis
+        assert!(serde_json::from_str::<DurationEx>(r#"{ "secs": 90, "decis": 10 }"#).is_err());
+        // missing field
+        assert!(serde_json::from_str::<DurationEx>(r#"{ "secs": 90 }"#).is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let ex: DurationEx = Duration::from_millis(3_723_400).into();
+        let json = serde_json::to_string(&ex).unwrap();
+        let ex2: DurationEx = serde_json::from_str(&json).unwrap();
+        assert_eq!(ex, ex2);
+    }
 }
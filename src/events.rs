@@ -18,6 +18,7 @@ pub enum Event {
     Tick,
     Render,
     Key(KeyEvent),
+    Paste(String),
     Resize,
 }
 
@@ -67,6 +68,7 @@ fn crossterm_stream() -> Pin<Box<dyn Stream<Item = Event>>> {
                     Ok(CrosstermEvent::Key(key)) if key.kind == KeyEventKind::Press => {
                         Some(Event::Key(key))
                     }
+                    Ok(CrosstermEvent::Paste(text)) => Some(Event::Paste(text)),
                     Ok(CrosstermEvent::Resize(_, _)) => Some(Event::Resize),
                     Err(_) => Some(Event::Error),
                     _ => None,
@@ -18,6 +18,7 @@ use args::Args;
 use clap::Parser;
 use color_eyre::Result;
 use config::Config;
+use std::io;
 use storage::{AppStorage, Storage};
 
 #[tokio::main]
@@ -28,11 +29,16 @@ async fn main() -> Result<()> {
 
     color_eyre::install()?;
 
-    let terminal = terminal::setup()?;
-    let events = events::Events::new();
-
     // get args given by CLI
-    let args = Args::parse();
+    let mut args = Args::parse();
+    // read stdin before setting up the terminal to exit with a plain error
+    if args.stdin {
+        args.duration = Some(args::read_duration(io::stdin().lock())?);
+    }
+
+    let set_title = args.title;
+    let terminal = terminal::setup(set_title)?;
+    let events = events::Events::new();
 
     // check persistant storage
     let storage = Storage::new(data_dir);
@@ -49,7 +55,7 @@ async fn main() -> Result<()> {
     // store app state persistantly
     storage.save(app_storage)?;
 
-    terminal::teardown()?;
+    terminal::teardown(set_title)?;
 
     Ok(())
 }
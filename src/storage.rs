@@ -1,19 +1,33 @@
 use crate::{
-    common::{Content, Style},
+    common::{Content, DigitFont, Style},
     constants::APP_NAME,
     widgets::pomodoro::Mode as PomodoroMode,
 };
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{ensure, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 use std::time::Duration;
 
+/// Current version of the schema of `AppStorage`.
+/// Increase it for any breaking change and add a migration to `AppStorage::from_json`.
+pub const STORAGE_VERSION: u32 = 1;
+
+// Data stored before versioning has been introduced are handled as version 1
+fn default_version() -> u32 {
+    1
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppStorage {
+    #[serde(default = "default_version")]
+    pub version: u32,
     pub content: Content,
     pub show_menu: bool,
     pub style: Style,
+    // added w/o a version bump, missing in data of older versions
+    #[serde(default)]
+    pub font: DigitFont,
     pub with_decis: bool,
     pub pomodoro_mode: PomodoroMode,
     // pomodoro -> work
@@ -27,6 +41,9 @@ pub struct AppStorage {
     pub current_value_countdown: Duration,
     // timer
     pub current_value_timer: Duration,
+    // counter, added w/o a version bump
+    #[serde(default)]
+    pub current_value_counter: u64,
 }
 
 impl Default for AppStorage {
@@ -35,9 +52,11 @@ impl Default for AppStorage {
         const DEFAULT_PAUSE: Duration = Duration::from_secs(60 * 5); /* 5min */
         const DEFAULT_COUNTDOWN: Duration = Duration::from_secs(60 * 10); /* 10min */
         AppStorage {
+            version: STORAGE_VERSION,
             content: Content::default(),
             show_menu: false,
             style: Style::default(),
+            font: DigitFont::default(),
             with_decis: false,
             pomodoro_mode: PomodoroMode::Work,
             // pomodoro -> work
@@ -51,10 +70,34 @@ impl Default for AppStorage {
             current_value_countdown: DEFAULT_COUNTDOWN,
             // timer
             current_value_timer: Duration::ZERO,
+            // counter
+            current_value_counter: 0,
         }
     }
 }
 
+impl AppStorage {
+    /// Parses stored data and migrates them to current `STORAGE_VERSION` (if needed).
+    /// Data of unknown (future) versions are rejected.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(default_version() as u64);
+        ensure!(
+            version <= STORAGE_VERSION as u64,
+            "Unsupported version {} of stored data (max. supported version: {}). Please update {} or run it with `--reset`.",
+            version,
+            STORAGE_VERSION,
+            APP_NAME
+        );
+        // version 1 is current
+        let data = serde_json::from_value(value)?;
+        Ok(data)
+    }
+}
+
 pub struct Storage {
     data_dir: PathBuf,
 }
@@ -75,8 +118,62 @@ impl Storage {
     }
 
     pub fn load(&self) -> Result<AppStorage> {
-        let file = fs::File::open(self.get_storage_path())?;
-        let data = serde_json::from_reader(file)?;
-        Ok(data)
+        let json = fs::read_to_string(self.get_storage_path())?;
+        AppStorage::from_json(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const DATA_V1: &str = r#"{
+        "version": 1,
+        "content": "Timer",
+        "show_menu": true,
+        "style": "Braille",
+        "with_decis": true,
+        "pomodoro_mode": "Pause",
+        "inital_value_work": { "secs": 1500, "nanos": 0 },
+        "current_value_work": { "secs": 1000, "nanos": 0 },
+        "inital_value_pause": { "secs": 300, "nanos": 0 },
+        "current_value_pause": { "secs": 200, "nanos": 0 },
+        "inital_value_countdown": { "secs": 600, "nanos": 0 },
+        "current_value_countdown": { "secs": 500, "nanos": 0 },
+        "current_value_timer": { "secs": 42, "nanos": 0 }
+    }"#;
+
+    #[test]
+    fn test_load_version_1() {
+        let data = AppStorage::from_json(DATA_V1).unwrap();
+        assert_eq!(data.version, 1);
+        assert_eq!(data.content, Content::Timer);
+        assert_eq!(data.style, Style::Braille);
+        assert_eq!(data.pomodoro_mode, PomodoroMode::Pause);
+        assert_eq!(data.current_value_countdown, Duration::from_secs(500));
+        assert_eq!(data.current_value_timer, Duration::from_secs(42));
+    }
+
+    #[test]
+    fn test_load_without_version() {
+        let json = DATA_V1.replace(r#""version": 1,"#, "");
+        let data = AppStorage::from_json(&json).unwrap();
+        assert_eq!(data.version, 1);
+    }
+
+    #[test]
+    fn test_load_unknown_version() {
+        let json = DATA_V1.replace(r#""version": 1,"#, r#""version": 999,"#);
+        let err = AppStorage::from_json(&json).unwrap_err().to_string();
+        assert!(err.contains("Unsupported version 999"));
+        assert!(err.contains("--reset"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let json = serde_json::to_string(&AppStorage::default()).unwrap();
+        let data = AppStorage::from_json(&json).unwrap();
+        assert_eq!(data.version, STORAGE_VERSION);
     }
 }
@@ -2,25 +2,93 @@ use std::io;
 
 use color_eyre::eyre::Result;
 use crossterm::{
-    cursor, execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+    cursor,
+    event::{DisableBracketedPaste, EnableBracketedPaste},
+    execute,
+    style::Print,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, SetTitle},
 };
 use ratatui::{backend::CrosstermBackend, Terminal as RatatuiTerminal};
 
 pub type Terminal = RatatuiTerminal<CrosstermBackend<io::Stdout>>;
 
-pub fn setup() -> Result<Terminal> {
+// xterm sequences to save / restore the title of the terminal on a stack.
+// Terminals which don't support them just ignore them.
+const PUSH_TITLE: &str = "\x1b[22;0t";
+const POP_TITLE: &str = "\x1b[23;0t";
+
+/// Sets up the terminal. The title of the terminal is saved if the app is going to change it.
+pub fn setup(save_title: bool) -> Result<Terminal> {
     let mut stdout = std::io::stdout();
     crossterm::terminal::enable_raw_mode()?;
-    execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableBracketedPaste,
+        cursor::Hide
+    )?;
+    if save_title {
+        execute!(stdout, Print(PUSH_TITLE))?;
+    }
     let mut terminal = RatatuiTerminal::new(CrosstermBackend::new(stdout))?;
     terminal.clear()?;
     terminal.hide_cursor()?;
     Ok(terminal)
 }
 
-pub fn teardown() -> Result<()> {
-    execute!(io::stdout(), LeaveAlternateScreen, cursor::Show)?;
+/// Restores the terminal. A title set by the app is cleared (if the saved one can't be restored).
+pub fn teardown(restore_title: bool) -> Result<()> {
+    if restore_title {
+        execute!(io::stdout(), SetTitle(""), Print(POP_TITLE))?;
+    }
+    execute!(
+        io::stdout(),
+        DisableBracketedPaste,
+        LeaveAlternateScreen,
+        cursor::Show
+    )?;
     crossterm::terminal::disable_raw_mode()?;
     Ok(())
 }
+
+pub fn set_title(title: &str) -> Result<()> {
+    execute!(io::stdout(), SetTitle(title))?;
+    Ok(())
+}
+
+/// Whether the background of the terminal is dark, detected by `COLORFGBG` (e.g. `15;0`).
+/// It's handled as dark if it can't be detected.
+pub fn background_is_dark() -> bool {
+    background_is_dark_from(std::env::var("COLORFGBG").ok())
+}
+
+// Background is the last field of `COLORFGBG` as ANSI color,
+// `7` (white) and `9`-`15` (bright colors) are light ones
+fn background_is_dark_from(colorfgbg: Option<String>) -> bool {
+    colorfgbg
+        .as_deref()
+        .and_then(|v| v.rsplit(';').next())
+        .and_then(|bg| bg.trim().parse::<u8>().ok())
+        .is_none_or(|bg| !matches!(bg, 7 | 9..=15))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_background_is_dark_from() {
+        let is_dark = |v: &str| background_is_dark_from(Some(v.to_string()));
+        assert!(is_dark("15;0"));
+        assert!(is_dark("7;8"));
+        assert!(!is_dark("0;15"));
+        assert!(!is_dark("0;7"));
+        // w/ default color in between (rxvt)
+        assert!(!is_dark("0;default;15"));
+        // unknown
+        assert!(is_dark("default;default"));
+        assert!(is_dark(""));
+        assert!(background_is_dark_from(None));
+    }
+}
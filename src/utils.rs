@@ -73,4 +73,40 @@ mod tests {
         ]);
         assert_eq!(b, expected);
     }
+
+    #[test]
+    fn test_center_horizontal_leftover() {
+        // even leftover space
+        let area = center_horizontal(Rect::new(0, 0, 10, 1), Constraint::Length(4));
+        assert_eq!(area, Rect::new(3, 0, 4, 1));
+        // odd leftover space: extra column goes to the left
+        let area = center_horizontal(Rect::new(0, 0, 11, 1), Constraint::Length(4));
+        assert_eq!(area, Rect::new(4, 0, 4, 1));
+        // no leftover space
+        let area = center_horizontal(Rect::new(2, 0, 4, 1), Constraint::Length(4));
+        assert_eq!(area, Rect::new(2, 0, 4, 1));
+    }
+
+    #[test]
+    fn test_center_vertical_leftover() {
+        // even leftover space
+        let area = center_vertical(Rect::new(0, 0, 1, 10), Constraint::Length(4));
+        assert_eq!(area, Rect::new(0, 3, 1, 4));
+        // odd leftover space: extra row goes to the top
+        let area = center_vertical(Rect::new(0, 0, 1, 11), Constraint::Length(4));
+        assert_eq!(area, Rect::new(0, 4, 1, 4));
+        // no leftover space
+        let area = center_vertical(Rect::new(0, 2, 1, 4), Constraint::Length(4));
+        assert_eq!(area, Rect::new(0, 2, 1, 4));
+    }
+
+    #[test]
+    fn test_center_leftover() {
+        let area = center(
+            Rect::new(0, 0, 11, 10),
+            Constraint::Length(4),
+            Constraint::Length(5),
+        );
+        assert_eq!(area, Rect::new(4, 3, 4, 5));
+    }
 }
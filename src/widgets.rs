@@ -5,7 +5,10 @@ pub mod clock_elements_test;
 #[cfg(test)]
 pub mod clock_test;
 pub mod countdown;
+pub mod counter;
 pub mod footer;
+#[cfg(test)]
+pub mod golden_test;
 pub mod header;
 pub mod pomodoro;
 pub mod progressbar;
@@ -1,31 +1,31 @@
+use clap::ValueEnum;
+use color_eyre::{eyre::Result, Report};
 use std::fmt;
 use std::marker::PhantomData;
-use std::time::Duration;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 use strum::Display;
 
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style as RatatuiStyle},
+    text::{Line, Span},
     widgets::{StatefulWidget, Widget},
 };
 
 use crate::{
-    common::Style,
-    duration::{
-        DurationEx, MINS_PER_HOUR, ONE_DECI_SECOND, ONE_HOUR, ONE_MINUTE, ONE_SECOND,
-        SECS_PER_MINUTE,
-    },
+    common::{DigitFont, Style},
+    config::Settings,
+    constants::TICK_VALUE_MS,
+    duration::{DurationEx, MAX_DURATION, ONE_DECI_SECOND, ONE_HOUR, ONE_MINUTE, ONE_SECOND},
     utils::center_horizontal,
     widgets::clock_elements::{
-        Colon, Digit, Dot, COLON_WIDTH, DIGIT_HEIGHT, DIGIT_WIDTH, DOT_WIDTH,
+        get_digit_height, Colon, Digit, Dot, COLON_WIDTH, DIGIT_SIZE, DIGIT_WIDTH, DOT_WIDTH,
     },
 };
 
-// max. 99:59:59
-const MAX_DURATION: Duration =
-    Duration::from_secs(100 * MINS_PER_HOUR * SECS_PER_MINUTE).saturating_sub(ONE_SECOND);
-
-#[derive(Debug, Copy, Clone, Display, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Display, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Time {
     Decis,
     Seconds,
@@ -62,16 +62,69 @@ impl fmt::Display for Mode {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Display, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display, PartialOrd, Ord, ValueEnum)]
 pub enum Format {
+    #[value(name = "s")]
     S,
+    #[value(name = "ss")]
     Ss,
+    #[value(name = "m:ss")]
     MSs,
+    #[value(name = "mm:ss")]
     MmSs,
+    #[value(name = "h:mm:ss")]
     HMmSs,
+    #[value(name = "hh:mm:ss")]
     HhMmSs,
 }
 
+impl Format {
+    /// Most-significant `Time` shown by a format
+    pub fn max_visible_field(&self) -> Time {
+        match self {
+            Format::HhMmSs | Format::HMmSs => Time::Hours,
+            Format::MmSs | Format::MSs => Time::Minutes,
+            Format::Ss | Format::S => Time::Seconds,
+        }
+    }
+}
+
+/// What a `Clock<Countdown>` does after reaching zero
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum FinishAction {
+    #[default]
+    Stop,
+    /// Start again from `initial_value`
+    Repeat,
+    /// Start next value of `presets` (if any)
+    NextPreset,
+}
+
+/// Kind of a `Clock` (e.g. `Countdown`), needed by generic code of `Clock<T>`
+pub trait ClockKind {
+    /// Whether `tick` counts down to zero (or up to `MAX_DURATION`)
+    const COUNTS_DOWN: bool;
+}
+
+/// State of a clock which is optional (e.g. set by `set_presets`)
+/// or needed by some features only
+#[derive(Debug, Clone, Default)]
+struct Extras {
+    on_finish: FinishAction,
+    presets: Vec<DurationEx>,
+    preset_index: usize,
+    // latch to signal a finish once (see `just_finished`)
+    just_finished: bool,
+    // remaining times to announce (see `set_checkpoints`) + whether they have been fired
+    checkpoints: Vec<(DurationEx, bool)>,
+    // format to show instead of switching it by value (see `set_fixed_format`)
+    fixed_format: Option<Format>,
+    // values recorded by `Clock<Timer>::lap`
+    laps: Vec<DurationEx>,
+    // most-significant field changed by last `tick` (cleared by next one)
+    last_changed: Option<Time>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Clock<T> {
     initial_value: DurationEx,
@@ -81,6 +134,11 @@ pub struct Clock<T> {
     format: Format,
     pub style: Style,
     pub with_decis: bool,
+    extras: Extras,
+    // real time of creation + time running (see `active_elapsed`)
+    started_at: Instant,
+    active_time: Duration,
+    active_since: Option<Instant>,
     phantom: PhantomData<T>,
 }
 
@@ -92,13 +150,103 @@ pub struct ClockArgs {
     pub with_decis: bool,
 }
 
-impl<T> Clock<T> {
+impl<T: ClockKind> Clock<T> {
     pub fn toggle_pause(&mut self) {
-        self.mode = if self.mode == Mode::Tick {
+        let mode = if self.mode == Mode::Tick {
             Mode::Pause
         } else {
             Mode::Tick
+        };
+        self.change_mode(mode);
+    }
+
+    // Sets `Mode` and keeps `active_elapsed` up to date.
+    // Any change of `Mode` has to go through it.
+    fn change_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+        self.track_active_time();
+    }
+
+    /// Most-significant field (e.g. `Minutes` for `1:00` -> `0:59`) changed by last `tick`.
+    /// Any next `tick` clears it, even if the clock is not running.
+    pub fn last_changed(&self) -> Option<Time> {
+        self.extras.last_changed
+    }
+
+    // Most-significant field which differs between two values
+    fn get_changed_field(prev: &DurationEx, current: &DurationEx) -> Option<Time> {
+        if prev.hours() != current.hours() {
+            Some(Time::Hours)
+        } else if prev.minutes_mod() != current.minutes_mod() {
+            Some(Time::Minutes)
+        } else if prev.seconds_mod() != current.seconds_mod() {
+            Some(Time::Seconds)
+        } else if prev.decis() != current.decis() {
+            Some(Time::Decis)
+        } else {
+            None
+        }
+    }
+
+    // Adds a finished running span to `active_time` or starts a new one (see `change_mode`)
+    fn track_active_time(&mut self) {
+        match (&self.mode, self.active_since) {
+            (Mode::Tick, None) => self.active_since = Some(Instant::now()),
+            (Mode::Tick, Some(_)) => {}
+            (_, Some(since)) => {
+                self.active_time += since.elapsed();
+                self.active_since = None;
+            }
+            (_, None) => {}
+        }
+    }
+
+    /// Real time since the clock has been created (incl. paused times)
+    pub fn wall_elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Real time the clock has been running since it has been created (w/o paused times)
+    pub fn active_elapsed(&self) -> Duration {
+        self.active_time
+            + self
+                .active_since
+                .map_or(Duration::ZERO, |since| since.elapsed())
+    }
+
+    /// Types digit `d` (`0..=9`) into the field being edited, like on a digital timer:
+    /// The field's digits are shifted to the left (e.g. `05` + `7` -> `57`) and
+    /// clamped to its max. (`59` for minutes and seconds, `99` for hours).
+    /// Deciseconds have a single digit only, which is replaced.
+    /// A countdown handles exceeding `initial_value` like `edit_up` does.
+    pub fn edit_input_digit(&mut self, d: u8) {
+        let Mode::Editable(time, _) = &self.mode else {
+            return;
+        };
+        if d > 9 {
+            return;
+        }
+        let d = d as u64;
+        let (value, max, unit) = match time {
+            Time::Decis => (self.current_value.decis(), 9, ONE_DECI_SECOND),
+            Time::Seconds => (self.current_value.seconds_mod(), 59, ONE_SECOND),
+            Time::Minutes => (self.current_value.minutes_mod(), 59, ONE_MINUTE),
+            Time::Hours => (self.current_value.hours(), 99, ONE_HOUR),
+        };
+        let next = match time {
+            Time::Decis => d,
+            _ => ((value % 10) * 10 + d).min(max),
+        };
+        let current = Duration::from(self.current_value)
+            .saturating_sub(unit * value as u32)
+            .saturating_add(unit * next as u32)
+            .min(MAX_DURATION);
+        self.current_value = current.into();
+        if T::COUNTS_DOWN && self.initial_value.lt(&self.current_value) {
+            self.current_value = self.initial_value;
         }
+        self.update_format();
+        self.update_mode();
     }
 
     pub fn get_initial_value(&self) -> &DurationEx {
@@ -109,24 +257,11 @@ impl<T> Clock<T> {
         &self.current_value
     }
 
+    /// Enters or exits edit mode.
+    /// A running clock does not tick while editing, but continues after exiting.
     pub fn toggle_edit(&mut self) {
-        self.mode = match self.mode.clone() {
-            Mode::Editable(_, prev) => {
-                let p = *prev;
-                // special cases: Should `Mode` be updated?
-                // 1. `Done` -> `Initial` ?
-                if p == Mode::Done && self.current_value.gt(&Duration::ZERO.into()) {
-                    Mode::Initial
-                }
-                // 2. `_` -> `Done` ?
-                else if p != Mode::Done && self.current_value.eq(&Duration::ZERO.into()) {
-                    Mode::Done
-                }
-                // 3. `_` -> `_` (no change)
-                else {
-                    p
-                }
-            }
+        let mode = match self.mode.clone() {
+            Mode::Editable(_, prev) => self.get_mode_after_edit(*prev),
             mode => {
                 if self.format <= Format::Ss {
                     Mode::Editable(Time::Seconds, Box::new(mode))
@@ -135,6 +270,26 @@ impl<T> Clock<T> {
                 }
             }
         };
+        self.change_mode(mode);
+    }
+
+    // `Mode` to continue with after `current_value` has been set by an user
+    // (e.g. by editing), based on the `Mode` before
+    fn get_mode_after_edit(&self, prev: Mode) -> Mode {
+        let done = self.is_at_done_value();
+        // special cases: Should `Mode` be updated?
+        // 1. `Done` -> `Initial` ?
+        if prev == Mode::Done && !done {
+            Mode::Initial
+        }
+        // 2. `_` -> `Done` ?
+        else if prev != Mode::Done && done {
+            Mode::Done
+        }
+        // 3. `_` -> `_` (no change)
+        else {
+            prev
+        }
     }
 
     pub fn edit_current_up(&mut self) {
@@ -218,8 +373,7 @@ impl<T> Clock<T> {
     }
 
     fn edit_mode_next(&mut self) {
-        let mode = self.mode.clone();
-        self.mode = match mode {
+        let mode = match self.mode.clone() {
             Mode::Editable(Time::Decis, prev) => Mode::Editable(Time::Seconds, prev),
             Mode::Editable(Time::Seconds, prev) if self.format <= Format::Ss && self.with_decis => {
                 Mode::Editable(Time::Decis, prev)
@@ -241,14 +395,14 @@ impl<T> Clock<T> {
                 Mode::Editable(Time::Decis, prev)
             }
             Mode::Editable(Time::Hours, prev) => Mode::Editable(Time::Seconds, prev),
-            _ => mode,
+            mode => mode,
         };
+        self.change_mode(mode);
         self.update_format();
     }
 
     fn edit_mode_prev(&mut self) {
-        let mode = self.mode.clone();
-        self.mode = match mode {
+        let mode = match self.mode.clone() {
             Mode::Editable(Time::Decis, prev) if self.format <= Format::Ss => {
                 Mode::Editable(Time::Seconds, prev)
             }
@@ -272,58 +426,172 @@ impl<T> Clock<T> {
             }
             Mode::Editable(Time::Minutes, prev) => Mode::Editable(Time::Seconds, prev),
             Mode::Editable(Time::Hours, prev) => Mode::Editable(Time::Minutes, prev),
-            _ => mode,
+            mode => mode,
         };
+        self.change_mode(mode);
         self.update_format();
     }
 
     fn update_mode(&mut self) {
-        let mode = self.mode.clone();
-        self.mode = match mode {
-            Mode::Editable(Time::Hours, prev) if self.format <= Format::MmSs => {
-                Mode::Editable(Time::Minutes, prev)
-            }
-            Mode::Editable(Time::Minutes, prev) if self.format <= Format::Ss => {
-                Mode::Editable(Time::Seconds, prev)
+        let max_field = self.format.max_visible_field();
+        // move to a field which is still visible
+        if let Mode::Editable(time, prev) = &self.mode {
+            if *time > max_field {
+                let mode = Mode::Editable(max_field, prev.clone());
+                self.change_mode(mode);
             }
-            _ => mode,
         }
     }
 
     pub fn reset(&mut self) {
-        self.mode = Mode::Initial;
+        self.change_mode(Mode::Initial);
+        self.extras.just_finished = false;
+        self.extras.laps.clear();
+        self.extras.last_changed = None;
+        self.rearm_checkpoints();
+        // presets are started over with the first one
+        if let Some(first) = self.extras.presets.first() {
+            self.extras.preset_index = 0;
+            self.initial_value = *first;
+        }
         self.current_value = self.initial_value;
         self.update_format();
     }
 
+    fn rearm_checkpoints(&mut self) {
+        for (_, fired) in self.extras.checkpoints.iter_mut() {
+            *fired = false;
+        }
+    }
+
+    /// Sets the clock from a string like `"90"`, `"1:30"`, `"1:30:00"` or `"1h30m15s"`
+    /// (see `DurationEx::from_str`). A countdown starts from it as well (`initial_value`).
+    /// `Mode` is updated the same way as by exiting edit mode (e.g. `0` is `Done`).
+    /// Invalid input returns an error and leaves the clock untouched.
+    pub fn set_from_str(&mut self, s: &str) -> Result<()> {
+        let value: DurationEx = s.parse()?;
+        if T::COUNTS_DOWN {
+            self.initial_value = value;
+        }
+        self.current_value = value;
+        self.update_format();
+        // while editing, it's updated by exiting edit mode
+        if !self.is_edit_mode() {
+            let mode = self.get_mode_after_edit(self.mode.clone());
+            self.change_mode(mode);
+        }
+        self.update_mode();
+        Ok(())
+    }
+
+    /// Like `reset`, but restores `style` and `with_decis`
+    /// from given `Settings` as well
+    pub fn reset_to_defaults(&mut self, settings: &Settings) {
+        self.style = settings.style;
+        self.with_decis = settings.with_decis;
+        self.extras.fixed_format = settings.fixed_format;
+        self.reset();
+    }
+
     pub fn is_done(&self) -> bool {
         self.mode == Mode::Done
     }
 
+    // Whether `current_value` is at the value a clock is done at:
+    // zero for a countdown, max. for a timer
+    fn is_at_done_value(&self) -> bool {
+        if T::COUNTS_DOWN {
+            self.current_value.eq(&Duration::ZERO.into())
+        } else {
+            self.current_value.ge(&MAX_DURATION.into())
+        }
+    }
+
+    // Keeps `Mode` coherent with a `current_value` changed by hand (and not by `tick`):
+    // `Done` at the value a clock is done at, `Pause` if a done clock has left it.
+    // It's not a finish, so nothing is repeated, counted or signaled.
+    fn sync_done_mode(&mut self) {
+        match (&self.mode, self.is_at_done_value()) {
+            (Mode::Done, false) => self.change_mode(Mode::Pause),
+            (Mode::Initial | Mode::Tick | Mode::Pause, true) => self.change_mode(Mode::Done),
+            _ => {}
+        }
+    }
+
     fn update_format(&mut self) {
         self.format = self.get_format();
     }
 
+    /// `current_value` in the resolution it's displayed with (seconds or deciseconds).
+    /// A countdown is rounded up (e.g. `4.9s` is displayed as `5`), so it shows `0`
+    /// when it's done only. A timer is rounded down like a stopwatch.
+    /// It's for display only, everything else (e.g. `Done`) is based on `current_value`.
+    pub fn display_value(&self) -> DurationEx {
+        let resolution = if self.with_decis {
+            ONE_DECI_SECOND
+        } else {
+            ONE_SECOND
+        }
+        .as_millis();
+        let millis = self.current_value.millis();
+        let rounded = if T::COUNTS_DOWN {
+            millis.div_ceil(resolution) * resolution
+        } else {
+            millis / resolution * resolution
+        };
+        Duration::from_millis(rounded as u64)
+            .min(MAX_DURATION)
+            .into()
+    }
+
     pub fn get_format(&self) -> Format {
-        if self.current_value.hours() >= 10 {
+        self.get_format_of(&self.current_value)
+    }
+
+    /// `Format` of `display_value`, which might be larger than `get_format`
+    /// (e.g. `9:59.9` is displayed as `10:00`). Clocks are laid out and rendered by it.
+    pub fn get_display_format(&self) -> Format {
+        self.get_format_of(&self.display_value())
+    }
+
+    fn get_format_of(&self, value: &DurationEx) -> Format {
+        let format = if value.hours() >= 10 {
             Format::HhMmSs
-        } else if self.current_value.hours() >= 1 {
+        } else if value.hours() >= 1 {
             Format::HMmSs
-        } else if self.current_value.minutes() >= 10 {
+        } else if value.minutes() >= 10 {
             Format::MmSs
-        } else if self.current_value.minutes() >= 1 {
+        } else if value.minutes() >= 1 {
             Format::MSs
-        } else if self.current_value.seconds() >= 10 {
+        } else if value.seconds() >= 10 {
             Format::Ss
         } else {
             Format::S
+        };
+        match self.extras.fixed_format {
+            // a value is never cut off, e.g. `1:00:00` needs hours in any case
+            Some(fixed) => fixed.max(format),
+            None => format,
         }
     }
+
+    /// Shows all values in given `Format` (e.g. `HhMmSs` for `00:00:05`)
+    /// to keep the same width instead of switching it at `10:00`, `1:00:00` etc.
+    /// Values which need a larger format still get it.
+    pub fn set_fixed_format(&mut self, format: Option<Format>) {
+        self.extras.fixed_format = format;
+        self.update_format();
+        self.update_mode();
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Countdown {}
 
+impl ClockKind for Countdown {
+    const COUNTS_DOWN: bool = true;
+}
+
 impl Clock<Countdown> {
     pub fn new(args: ClockArgs) -> Self {
         let ClockArgs {
@@ -333,6 +601,9 @@ impl Clock<Countdown> {
             style,
             with_decis,
         } = args;
+        // max. 99:59:59
+        let initial_value = initial_value.min(MAX_DURATION);
+        let current_value = current_value.min(MAX_DURATION);
         let mut instance = Self {
             initial_value: initial_value.into(),
             current_value: current_value.into(),
@@ -345,6 +616,10 @@ impl Clock<Countdown> {
                 Mode::Pause
             },
             format: Format::S,
+            extras: Extras::default(),
+            started_at: Instant::now(),
+            active_time: Duration::ZERO,
+            active_since: None,
             style,
             with_decis,
             phantom: PhantomData,
@@ -354,18 +629,167 @@ impl Clock<Countdown> {
         instance
     }
 
-    pub fn tick(&mut self) {
+    /// Countdown as configured by `Settings` (incl. checkpoints and presets)
+    /// with a tick of `TICK_VALUE_MS`, which continues at `current_value` (e.g. of last session).
+    /// It starts with the first preset (if any) and runs all of them one after another.
+    pub fn from_config(settings: &Settings, current_value: Duration) -> Self {
+        let mut clock = Self::new(ClockArgs {
+            initial_value: settings.countdown,
+            current_value,
+            tick_value: Duration::from_millis(TICK_VALUE_MS),
+            style: settings.style,
+            with_decis: settings.with_decis,
+        });
+        clock.set_fixed_format(settings.fixed_format);
+        clock.set_checkpoints(settings.checkpoints.clone());
+        if !settings.presets.is_empty() {
+            clock.set_presets(settings.presets.clone());
+            clock.set_on_finish(FinishAction::NextPreset);
+        } else if settings.repeat {
+            clock.set_on_finish(FinishAction::Repeat);
+        }
+        clock
+    }
+
+    /// Counts down by `tick_value` and returns a checkpoint crossed by this tick (if any).
+    /// If a tick jumps over more than one checkpoint, the smallest one is returned
+    /// and all of them are fired.
+    pub fn tick(&mut self) -> Option<Duration> {
+        self.extras.last_changed = None;
         if self.mode == Mode::Tick {
+            // re-ticking a finished clock (e.g. after `toggle_pause`) is not another finish
+            if self.current_value.eq(&Duration::ZERO.into()) {
+                self.change_mode(Mode::Done);
+                return None;
+            }
+            let prev_value = self.current_value;
             self.current_value = self.current_value.saturating_sub(self.tick_value);
+            let checkpoint = self.fire_checkpoints(prev_value);
+            self.extras.last_changed = Self::get_changed_field(&prev_value, &self.current_value);
             self.set_done();
             self.update_format();
+            checkpoint
+        } else {
+            None
         }
     }
 
+    /// Sets remaining times to announce (e.g. `30s` or `10s` left), see `tick`.
+    /// Each checkpoint is fired once per run.
+    pub fn set_checkpoints(&mut self, checkpoints: Vec<Duration>) {
+        self.extras.checkpoints = checkpoints
+            .into_iter()
+            .map(|c| (DurationEx::from(c), false))
+            .collect();
+    }
+
+    // Fires all checkpoints crossed between `prev_value` and `current_value`
+    // and returns the smallest of them (if any)
+    fn fire_checkpoints(&mut self, prev_value: DurationEx) -> Option<Duration> {
+        let current_value = self.current_value;
+        self.extras
+            .checkpoints
+            .iter_mut()
+            .filter(|(checkpoint, fired)| {
+                !fired && current_value.le(checkpoint) && prev_value.gt(checkpoint)
+            })
+            .map(|(checkpoint, fired)| {
+                *fired = true;
+                Duration::from(*checkpoint)
+            })
+            .min()
+    }
+
     fn set_done(&mut self) {
         if self.current_value.eq(&Duration::ZERO.into()) {
-            self.mode = Mode::Done;
+            match self.extras.on_finish {
+                FinishAction::Repeat if self.initial_value.gt(&Duration::ZERO.into()) => {
+                    self.current_value = self.initial_value;
+                    self.rearm_checkpoints();
+                }
+                FinishAction::NextPreset
+                    if self.extras.preset_index + 1 < self.extras.presets.len() =>
+                {
+                    self.extras.preset_index += 1;
+                    self.initial_value = self.extras.presets[self.extras.preset_index];
+                    self.current_value = self.initial_value;
+                    self.rearm_checkpoints();
+                }
+                _ => {
+                    self.extras.just_finished = true;
+                    self.change_mode(Mode::Done);
+                }
+            }
+        }
+    }
+
+    /// Whether the clock has been finished since last call.
+    /// It returns `true` once per finish only.
+    pub fn just_finished(&mut self) -> bool {
+        std::mem::take(&mut self.extras.just_finished)
+    }
+
+    pub fn set_on_finish(&mut self, on_finish: FinishAction) {
+        self.extras.on_finish = on_finish;
+    }
+
+    /// Sets a list of values to run one after another (see `FinishAction::NextPreset`).
+    /// The clock is reset to the first one.
+    pub fn set_presets(&mut self, presets: Vec<Duration>) {
+        self.extras.presets = presets.into_iter().map(DurationEx::from).collect();
+        if !self.extras.presets.is_empty() {
+            self.reset();
+        }
+    }
+
+    /// Restarts a done clock with `by` left (like the snooze of an alarm).
+    /// `initial_value` is raised by the same amount. It does nothing if the clock is not done.
+    pub fn snooze(&mut self, by: Duration) {
+        if !self.is_done() {
+            return;
+        }
+        let snoozed = |value: DurationEx| -> DurationEx {
+            Duration::from(value.saturating_add(by.into()))
+                .min(MAX_DURATION)
+                .into()
+        };
+        self.current_value = snoozed(self.current_value);
+        self.initial_value = snoozed(self.initial_value);
+        self.change_mode(Mode::Tick);
+        self.update_format();
+    }
+
+    /// Adds (positive `delta`) or subtracts (negative `delta`) seconds.
+    /// `initial_value` is raised if needed to keep `current_value` within it.
+    /// Reaching zero makes the clock `Done` (but it's not a finish, see `FinishAction`),
+    /// leaving it pauses a done clock.
+    pub fn apply_delta_secs(&mut self, delta: i64) {
+        let delta_ex: DurationEx = Duration::from_secs(delta.unsigned_abs()).into();
+        if delta >= 0 {
+            self.current_value = self.current_value.saturating_add(delta_ex);
+            if self.current_value.gt(&MAX_DURATION.into()) {
+                self.current_value = MAX_DURATION.into();
+            }
+            if self.initial_value.lt(&self.current_value) {
+                self.initial_value = self.current_value;
+            }
+        } else {
+            self.current_value = self.current_value.saturating_sub(delta_ex);
         }
+        self.sync_done_mode();
+        self.update_format();
+        self.update_mode();
+    }
+
+    /// Steps back in time by adding `by` to `current_value` (max. `initial_value`).
+    /// Unlike editing, `Mode` is kept, except a done clock, which is paused.
+    pub fn rewind(&mut self, by: Duration) {
+        self.current_value = self.current_value.saturating_add(by.into());
+        if self.initial_value.lt(&self.current_value) {
+            self.current_value = self.initial_value;
+        }
+        self.sync_done_mode();
+        self.update_format();
     }
 
     pub fn get_percentage_done(&self) -> u16 {
@@ -395,9 +819,30 @@ impl Clock<Countdown> {
     }
 }
 
+/// Countdown parsed from `ss`, `mm:ss` or `hh:mm:ss` (e.g. `"25:00".parse()`)
+/// with default style and a tick of `TICK_VALUE_MS`.
+impl FromStr for Clock<Countdown> {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value: Duration = s.parse::<DurationEx>()?.into();
+        Ok(Self::new(ClockArgs {
+            initial_value: value,
+            current_value: value,
+            tick_value: Duration::from_millis(TICK_VALUE_MS),
+            style: Style::default(),
+            with_decis: false,
+        }))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Timer {}
 
+impl ClockKind for Timer {
+    const COUNTS_DOWN: bool = false;
+}
+
 impl Clock<Timer> {
     pub fn new(args: ClockArgs) -> Self {
         let ClockArgs {
@@ -407,6 +852,9 @@ impl Clock<Timer> {
             style,
             with_decis,
         } = args;
+        // max. 99:59:59
+        let initial_value = initial_value.min(MAX_DURATION);
+        let current_value = current_value.min(MAX_DURATION);
         let mut instance = Self {
             initial_value: initial_value.into(),
             current_value: current_value.into(),
@@ -419,6 +867,10 @@ impl Clock<Timer> {
                 Mode::Pause
             },
             format: Format::S,
+            extras: Extras::default(),
+            started_at: Instant::now(),
+            active_time: Duration::ZERO,
+            active_since: None,
             phantom: PhantomData,
             style,
             with_decis,
@@ -429,8 +881,14 @@ impl Clock<Timer> {
     }
 
     pub fn tick(&mut self) {
+        self.extras.last_changed = None;
         if self.mode == Mode::Tick {
+            let prev_value = self.current_value;
             self.current_value = self.current_value.saturating_add(self.tick_value);
+            if self.current_value.gt(&MAX_DURATION.into()) {
+                self.current_value = MAX_DURATION.into();
+            }
+            self.extras.last_changed = Self::get_changed_field(&prev_value, &self.current_value);
             self.set_done();
             self.update_format();
         }
@@ -438,8 +896,51 @@ impl Clock<Timer> {
 
     fn set_done(&mut self) {
         if self.current_value.ge(&MAX_DURATION.into()) {
-            self.mode = Mode::Done;
+            self.change_mode(Mode::Done);
+        }
+    }
+
+    /// Records `current_value` as a lap (in any mode) without stopping the clock
+    pub fn lap(&mut self) {
+        self.extras.laps.push(self.current_value);
+    }
+
+    /// All laps recorded since last reset
+    pub fn laps(&self) -> &[DurationEx] {
+        &self.extras.laps
+    }
+
+    /// Time between the last two laps
+    pub fn last_split(&self) -> Option<DurationEx> {
+        match self.extras.laps.as_slice() {
+            [.., prev, last] => Some(last.saturating_sub(*prev)),
+            _ => None,
+        }
+    }
+
+    /// Adds (positive `delta`) or subtracts (negative `delta`) seconds.
+    /// Reaching the max. value makes the clock `Done`, leaving it pauses a done clock.
+    pub fn apply_delta_secs(&mut self, delta: i64) {
+        let delta_ex: DurationEx = Duration::from_secs(delta.unsigned_abs()).into();
+        if delta >= 0 {
+            self.current_value = self.current_value.saturating_add(delta_ex);
+            if self.current_value.gt(&MAX_DURATION.into()) {
+                self.current_value = MAX_DURATION.into();
+            }
+        } else {
+            self.current_value = self.current_value.saturating_sub(delta_ex);
         }
+        self.sync_done_mode();
+        self.update_format();
+        self.update_mode();
+    }
+
+    /// Steps back in time by subtracting `by` from `current_value` (min. `0`).
+    /// Unlike editing, `Mode` is kept, except a done clock, which is paused.
+    pub fn rewind(&mut self, by: Duration) {
+        self.current_value = self.current_value.saturating_sub(by.into());
+        self.sync_done_mode();
+        self.update_format();
     }
 
     pub fn edit_next(&mut self) {
@@ -459,26 +960,80 @@ impl Clock<Timer> {
     }
 }
 
-const SPACE_WIDTH: u16 = 1;
+pub const SPACE_WIDTH: u16 = 1;
+// rendered instead of digits if there is not enough space for any digit
+const TINY_INDICATOR: &str = "⏱";
 
 pub struct ClockWidget<T>
 where
-    T: std::fmt::Debug,
+    T: std::fmt::Debug + Clone + ClockKind,
 {
+    vertical_scale: u16,
+    background_is_dark: bool,
+    mono_digit: bool,
+    narrow_colons: bool,
+    emphasize_changes: bool,
+    font: DigitFont,
     phantom: PhantomData<T>,
 }
 
 impl<T> ClockWidget<T>
 where
-    T: std::fmt::Debug,
+    T: std::fmt::Debug + Clone + ClockKind,
 {
     pub fn new() -> Self {
         Self {
+            vertical_scale: 1,
+            background_is_dark: true,
+            mono_digit: false,
+            narrow_colons: false,
+            emphasize_changes: false,
+            font: DigitFont::default(),
             phantom: PhantomData,
         }
     }
 
+    /// Background of the terminal, used to pick a symbol with high contrast (see `Style::Auto`)
+    pub fn background_is_dark(mut self, is_dark: bool) -> Self {
+        self.background_is_dark = is_dark;
+        self
+    }
+
+    /// Font to draw digits with (see `DigitFont`)
+    pub fn font(mut self, font: DigitFont) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Renders numbers as plain text and colons only as blocks (see `HybridClockWidget`)
+    pub fn mono_digit(mut self, mono_digit: bool) -> Self {
+        self.mono_digit = mono_digit;
+        self
+    }
+
+    /// Number of terminal rows to draw each row of a digit with
+    pub fn vertical_scale(mut self, scale: u16) -> Self {
+        self.vertical_scale = scale.max(1);
+        self
+    }
+
+    /// Renders digits of the field changed by last tick bold until next tick (see `last_changed`)
+    pub fn emphasize_changes(mut self, emphasize: bool) -> Self {
+        self.emphasize_changes = emphasize;
+        self
+    }
+
+    fn get_colon_width(&self) -> u16 {
+        // `COLON_WIDTH` includes a padding of 1 on both sides
+        if self.narrow_colons {
+            COLON_WIDTH - 2
+        } else {
+            COLON_WIDTH
+        }
+    }
+
     fn get_horizontal_lengths(&self, format: &Format, with_decis: bool) -> Vec<u16> {
+        let colon_width = self.get_colon_width();
         let add_decis = |mut lengths: Vec<u16>, with_decis: bool| -> Vec<u16> {
             if with_decis {
                 lengths.extend_from_slice(&[
@@ -495,11 +1050,11 @@ where
                     DIGIT_WIDTH, // h
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // h
-                    COLON_WIDTH, // :
+                    colon_width, // :
                     DIGIT_WIDTH, // m
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // m
-                    COLON_WIDTH, // :
+                    colon_width, // :
                     DIGIT_WIDTH, // s
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // s
@@ -509,11 +1064,11 @@ where
             Format::HMmSs => add_decis(
                 vec![
                     DIGIT_WIDTH, // h
-                    COLON_WIDTH, // :
+                    colon_width, // :
                     DIGIT_WIDTH, // m
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // m
-                    COLON_WIDTH, // :
+                    colon_width, // :
                     DIGIT_WIDTH, // s
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // s
@@ -525,7 +1080,7 @@ where
                     DIGIT_WIDTH, // m
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // m
-                    COLON_WIDTH, // :
+                    colon_width, // :
                     DIGIT_WIDTH, // s
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // s
@@ -535,7 +1090,7 @@ where
             Format::MSs => add_decis(
                 vec![
                     DIGIT_WIDTH, // m
-                    COLON_WIDTH, // :
+                    colon_width, // :
                     DIGIT_WIDTH, // s
                     SPACE_WIDTH, // (space)
                     DIGIT_WIDTH, // s
@@ -560,180 +1115,345 @@ where
     }
 
     pub fn get_width(&self, format: &Format, with_decis: bool) -> u16 {
+        if self.mono_digit {
+            return HybridClockWidget::<T>::new().get_width(format, with_decis);
+        }
         self.get_horizontal_lengths(format, with_decis).iter().sum()
     }
 
     pub fn get_height(&self) -> u16 {
-        DIGIT_HEIGHT
+        if self.mono_digit {
+            return HybridClockWidget::<T>::new().get_height();
+        }
+        get_digit_height(self.font, self.vertical_scale)
     }
 }
 
 impl<T> StatefulWidget for ClockWidget<T>
 where
-    T: std::fmt::Debug,
+    T: std::fmt::Debug + Clone + ClockKind,
 {
     type State = Clock<T>;
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let last_changed = if self.emphasize_changes {
+            state.last_changed()
+        } else {
+            None
+        };
+        // values between displayed units (e.g. `4.9s` w/o deciseconds) are rounded
+        let mut displayed;
+        let state = if state.display_value() != state.current_value {
+            displayed = state.clone();
+            displayed.current_value = state.display_value();
+            displayed.format = state.get_display_format();
+            &mut displayed
+        } else {
+            state
+        };
+        // too small to show any digit, just show that the clock exists
+        // (scaled digits + border row)
+        if !self.mono_digit && (area.width < DIGIT_WIDTH || area.height < self.get_height()) {
+            let [_, center, _] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ])
+            .areas(area);
+            Line::raw(TINY_INDICATOR).centered().render(center, buf);
+            return;
+        }
+        // narrow colons (no padding) if space is too small
+        if area.width < self.get_width(&state.format, state.with_decis) {
+            self.narrow_colons = true;
+        }
+        if self.mono_digit {
+            HybridClockWidget::new()
+                .background_is_dark(self.background_is_dark)
+                .render(area, buf, state);
+            return;
+        }
         let with_decis = state.with_decis;
         let format = state.format;
-        let symbol = state.style.get_digit_symbol();
+        let symbol = state.style.get_digit_symbol_on(self.background_is_dark);
         let widths = self.get_horizontal_lengths(&format, with_decis);
         let area = center_horizontal(
             area,
             Constraint::Length(self.get_width(&format, with_decis)),
         );
-        let edit_hours = matches!(state.mode, Mode::Editable(Time::Hours, _));
-        let edit_minutes = matches!(state.mode, Mode::Editable(Time::Minutes, _));
-        let edit_secs = matches!(state.mode, Mode::Editable(Time::Seconds, _));
-        let edit_decis = matches!(state.mode, Mode::Editable(Time::Decis, _));
+        let edit_field = match state.mode {
+            Mode::Editable(time, _) => Some(time),
+            _ => None,
+        };
+        let scale = self.vertical_scale;
+        let braille = state.style == Style::Dots;
+        let half_height = !braille && self.font == DigitFont::HalfHeight;
+        let gradient = state.style == Style::Gradient;
+        let digit = |value: u64, field: Time| {
+            Digit::new(value, edit_field == Some(field), symbol)
+                .vertical_scale(scale)
+                .braille(braille)
+                .half_height(half_height)
+                .gradient(gradient)
+                .emphasize(last_changed == Some(field))
+        };
+        let colon = || {
+            Colon::new(symbol)
+                .vertical_scale(scale)
+                .braille(braille)
+                .half_height(half_height)
+        };
+        let dot = || {
+            Dot::new(symbol)
+                .vertical_scale(scale)
+                .braille(braille)
+                .half_height(half_height)
+        };
         match format {
             Format::HhMmSs if with_decis => {
                 let [hh, _, h, c_hm, mm, _, m, c_ms, ss, _, s, d, ds] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.hours() / 10, edit_hours, symbol).render(hh, buf);
-                Digit::new(state.current_value.hours() % 10, edit_hours, symbol).render(h, buf);
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(state.current_value.minutes_mod() / 10, edit_minutes, symbol)
-                    .render(mm, buf);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
-                Dot::new(symbol).render(d, buf);
-                Digit::new(state.current_value.decis(), edit_decis, symbol).render(ds, buf);
+                digit(state.current_value.hours() / 10, Time::Hours).render(hh, buf);
+                digit(state.current_value.hours() % 10, Time::Hours).render(h, buf);
+                colon().render(c_hm, buf);
+                digit(state.current_value.minutes_mod() / 10, Time::Minutes).render(mm, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+                dot().render(d, buf);
+                digit(state.current_value.decis(), Time::Decis).render(ds, buf);
             }
             Format::HhMmSs => {
                 let [hh, _, h, c_hm, mm, _, m, c_ms, ss, _, s] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.hours() / 10, edit_hours, symbol).render(hh, buf);
-                Digit::new(state.current_value.hours() % 10, edit_hours, symbol).render(h, buf);
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(state.current_value.minutes_mod() / 10, edit_minutes, symbol)
-                    .render(mm, buf);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
+                digit(state.current_value.hours() / 10, Time::Hours).render(hh, buf);
+                digit(state.current_value.hours() % 10, Time::Hours).render(h, buf);
+                colon().render(c_hm, buf);
+                digit(state.current_value.minutes_mod() / 10, Time::Minutes).render(mm, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
             }
             Format::HMmSs if with_decis => {
                 let [h, c_hm, mm, _, m, c_ms, ss, _, s, d, ds] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.hours() % 10, edit_hours, symbol).render(h, buf);
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(state.current_value.minutes_mod() / 10, edit_minutes, symbol)
-                    .render(mm, buf);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
-                Dot::new(symbol).render(d, buf);
-                Digit::new(state.current_value.decis(), edit_decis, symbol).render(ds, buf);
+                digit(state.current_value.hours() % 10, Time::Hours).render(h, buf);
+                colon().render(c_hm, buf);
+                digit(state.current_value.minutes_mod() / 10, Time::Minutes).render(mm, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+                dot().render(d, buf);
+                digit(state.current_value.decis(), Time::Decis).render(ds, buf);
             }
             Format::HMmSs => {
                 let [h, c_hm, mm, _, m, c_ms, ss, _, s] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.hours() % 10, edit_hours, symbol).render(h, buf);
-                Colon::new(symbol).render(c_hm, buf);
-                Digit::new(state.current_value.minutes_mod() / 10, edit_minutes, symbol)
-                    .render(mm, buf);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
+                digit(state.current_value.hours() % 10, Time::Hours).render(h, buf);
+                colon().render(c_hm, buf);
+                digit(state.current_value.minutes_mod() / 10, Time::Minutes).render(mm, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
             }
             Format::MmSs if with_decis => {
                 let [mm, _, m, c_ms, ss, _, s, d, ds] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.minutes_mod() / 10, edit_minutes, symbol)
-                    .render(mm, buf);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
-                Dot::new(symbol).render(d, buf);
-                Digit::new(state.current_value.decis(), edit_decis, symbol).render(ds, buf);
+                digit(state.current_value.minutes_mod() / 10, Time::Minutes).render(mm, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+                dot().render(d, buf);
+                digit(state.current_value.decis(), Time::Decis).render(ds, buf);
             }
             Format::MmSs => {
                 let [mm, _, m, c_ms, ss, _, s] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.minutes_mod() / 10, edit_minutes, symbol)
-                    .render(mm, buf);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
+                digit(state.current_value.minutes_mod() / 10, Time::Minutes).render(mm, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
             }
             Format::MSs if with_decis => {
                 let [m, c_ms, ss, _, s, d, ds] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
-                Dot::new(symbol).render(d, buf);
-                Digit::new(state.current_value.decis(), edit_decis, symbol).render(ds, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+                dot().render(d, buf);
+                digit(state.current_value.decis(), Time::Decis).render(ds, buf);
             }
             Format::MSs => {
                 let [m, c_ms, ss, _, s] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.minutes_mod() % 10, edit_minutes, symbol)
-                    .render(m, buf);
-                Colon::new(symbol).render(c_ms, buf);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
+                digit(state.current_value.minutes_mod() % 10, Time::Minutes).render(m, buf);
+                colon().render(c_ms, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
             }
             Format::Ss if state.with_decis => {
                 let [ss, _, s, d, ds] =
                     Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
-                Dot::new(symbol).render(d, buf);
-                Digit::new(state.current_value.decis(), edit_decis, symbol).render(ds, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+                dot().render(d, buf);
+                digit(state.current_value.decis(), Time::Decis).render(ds, buf);
             }
             Format::Ss => {
                 let [ss, _, s] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.seconds_mod() / 10, edit_secs, symbol)
-                    .render(ss, buf);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
+                digit(state.current_value.seconds_mod() / 10, Time::Seconds).render(ss, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
             }
             Format::S if with_decis => {
                 let [s, d, ds] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
-                Dot::new(symbol).render(d, buf);
-                Digit::new(state.current_value.decis(), edit_decis, symbol).render(ds, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+                dot().render(d, buf);
+                digit(state.current_value.decis(), Time::Decis).render(ds, buf);
             }
             Format::S => {
                 let [s] = Layout::horizontal(Constraint::from_lengths(widths)).areas(area);
-                Digit::new(state.current_value.seconds_mod() % 10, edit_secs, symbol)
-                    .render(s, buf);
+                digit(state.current_value.seconds_mod() % 10, Time::Seconds).render(s, buf);
+            }
+        }
+    }
+}
+
+/// Renders time as plain text, but colons as blocks (like `ClockWidget`)
+pub struct HybridClockWidget<T>
+where
+    T: std::fmt::Debug + ClockKind,
+{
+    background_is_dark: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<T> HybridClockWidget<T>
+where
+    T: std::fmt::Debug + ClockKind,
+{
+    pub fn new() -> Self {
+        Self {
+            background_is_dark: true,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Background of the terminal to pick the symbol of colons with (see `ClockWidget::background_is_dark`)
+    pub fn background_is_dark(mut self, is_dark: bool) -> Self {
+        self.background_is_dark = is_dark;
+        self
+    }
+
+    // widths of text groups (e.g. `hh`, `mm`, `ss`) separated by colons
+    fn get_group_widths(&self, format: &Format, with_decis: bool) -> Vec<u16> {
+        let mut widths = match format {
+            Format::HhMmSs => vec![2, 2, 2],
+            Format::HMmSs => vec![1, 2, 2],
+            Format::MmSs => vec![2, 2],
+            Format::MSs => vec![1, 2],
+            Format::Ss => vec![2],
+            Format::S => vec![1],
+        };
+        if with_decis {
+            if let Some(last) = widths.last_mut() {
+                *last += 2; // e.g. `.5`
+            }
+        }
+        widths
+    }
+
+    fn get_horizontal_lengths(&self, format: &Format, with_decis: bool) -> Vec<u16> {
+        let widths = self.get_group_widths(format, with_decis);
+        let mut lengths = Vec::new();
+        for (i, width) in widths.into_iter().enumerate() {
+            if i > 0 {
+                lengths.push(COLON_WIDTH);
+            }
+            lengths.push(width);
+        }
+        lengths
+    }
+
+    pub fn get_width(&self, format: &Format, with_decis: bool) -> u16 {
+        self.get_horizontal_lengths(format, with_decis).iter().sum()
+    }
+
+    pub fn get_height(&self) -> u16 {
+        // same height as colons
+        DIGIT_SIZE as u16
+    }
+}
+
+impl<T> StatefulWidget for HybridClockWidget<T>
+where
+    T: std::fmt::Debug + ClockKind,
+{
+    type State = Clock<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let symbol = state.style.get_digit_symbol_on(self.background_is_dark);
+        let braille = state.style == Style::Dots;
+        let lengths = self.get_horizontal_lengths(&state.format, state.with_decis);
+        let area = center_horizontal(
+            area,
+            Constraint::Length(self.get_width(&state.format, state.with_decis)),
+        );
+        let value = state.current_value;
+        // fixed fields are zero-padded, e.g. `00:00:05` by `Format::HhMmSs`
+        let (hours, minutes, seconds) = (value.hours(), value.minutes_mod(), value.seconds_mod());
+        let mut groups: Vec<String> = match state.format {
+            Format::HhMmSs => vec![
+                format!("{hours:02}"),
+                format!("{minutes:02}"),
+                format!("{seconds:02}"),
+            ],
+            Format::HMmSs => vec![
+                format!("{hours}"),
+                format!("{minutes:02}"),
+                format!("{seconds:02}"),
+            ],
+            Format::MmSs => vec![format!("{minutes:02}"), format!("{seconds:02}")],
+            Format::MSs => vec![format!("{minutes}"), format!("{seconds:02}")],
+            Format::Ss => vec![format!("{seconds:02}")],
+            Format::S => vec![format!("{seconds}")],
+        };
+        if state.with_decis {
+            if let Some(last) = groups.last_mut() {
+                last.push_str(&format!(".{}", state.current_value.decis()));
+            }
+        }
+        // index of edited group counted from the end (`ss` -> 0, `mm` -> 1, `hh` -> 2)
+        let edit_group = match state.mode {
+            Mode::Editable(Time::Decis | Time::Seconds, _) => Some(0),
+            Mode::Editable(Time::Minutes, _) => Some(1),
+            Mode::Editable(Time::Hours, _) => Some(2),
+            _ => None,
+        };
+        let areas = Layout::horizontal(Constraint::from_lengths(lengths)).split(area);
+        let count = groups.len();
+        for (i, group) in groups.into_iter().enumerate() {
+            // text in the middle row of colons
+            let text_area = areas[i * 2];
+            let text_area = Rect {
+                y: text_area.y + self.get_height() / 2,
+                height: 1,
+                ..text_area
+            };
+            let style = if edit_group == Some(count - 1 - i) {
+                RatatuiStyle::default().add_modifier(Modifier::UNDERLINED)
+            } else {
+                RatatuiStyle::default()
+            };
+            Span::styled(group, style).render(text_area, buf);
+            if let Some(colon_area) = areas.get(i * 2 + 1) {
+                Colon::new(symbol).braille(braille).render(*colon_area, buf);
             }
         }
     }
@@ -1,12 +1,17 @@
+use crate::common::DigitFont;
 use ratatui::{
     buffer::Buffer,
     layout::{Position, Rect},
+    style::{Color, Modifier},
+    symbols::shade,
     widgets::Widget,
 };
 
 pub const DIGIT_SIZE: usize = 5;
 pub const DIGIT_WIDTH: u16 = DIGIT_SIZE as u16;
 pub const DIGIT_HEIGHT: u16 = DIGIT_SIZE as u16 + 1 /* border height */;
+// two rows of a digit per cell (see `Digit::half_height`)
+pub const HALF_DIGIT_HEIGHT: u16 = DIGIT_SIZE.div_ceil(2) as u16 + 1 /* border height */;
 pub const COLON_WIDTH: u16 = 4; // incl. padding left + padding right
 pub const DOT_WIDTH: u16 = 4; // incl. padding left + padding right
 
@@ -109,10 +114,152 @@ const CHAR_E: [u8; DIGIT_SIZE * DIGIT_SIZE] = [
     1, 1, 1, 1, 1,
 ];
 
+// 2 columns (pixels) of a colon to be rendered as braille or half blocks
+#[rustfmt::skip]
+const COLON_BITMAP: [u8; 2 * DIGIT_SIZE] = [
+    0, 0,
+    1, 1,
+    0, 0,
+    1, 1,
+    0, 0,
+];
+
+// 2 columns (pixels) of a dot to be rendered as braille or half blocks
+#[rustfmt::skip]
+const DOT_BITMAP: [u8; 2 * DIGIT_SIZE] = [
+    0, 0,
+    0, 0,
+    0, 0,
+    0, 0,
+    1, 1,
+];
+
+// Bits of braille dots by position in a cell (2 columns x 4 rows)
+// https://en.wikipedia.org/wiki/Braille_Patterns#Identifying,_naming_and_ordering
+const BRAILLE_BITS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Maps a bitmap (`1` = set) with given width into rows of braille chars.
+/// Each char covers 2x4 pixels of the bitmap.
+fn to_braille(bitmap: &[u8], width: usize) -> Vec<Vec<char>> {
+    let height = bitmap.len() / width;
+    (0..height.div_ceil(4))
+        .map(|cy| {
+            (0..width.div_ceil(2))
+                .map(|cx| {
+                    let mut bits = 0;
+                    for (dy, row) in BRAILLE_BITS.iter().enumerate() {
+                        for (dx, bit) in row.iter().enumerate() {
+                            let (x, y) = (cx * 2 + dx, cy * 4 + dy);
+                            if x < width && y < height && bitmap[y * width + x] == 1 {
+                                bits |= bit;
+                            }
+                        }
+                    }
+                    char::from_u32(0x2800 + bits).unwrap_or(' ')
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Maps a bitmap (`1` = set) with given width into rows of half blocks.
+/// Each char covers 1x2 pixels of the bitmap.
+fn to_half_blocks(bitmap: &[u8], width: usize) -> Vec<Vec<char>> {
+    let height = bitmap.len() / width;
+    let is_set = |x: usize, y: usize| y < height && bitmap[y * width + x] == 1;
+    (0..height.div_ceil(2))
+        .map(|cy| {
+            (0..width)
+                .map(|x| match (is_set(x, cy * 2), is_set(x, cy * 2 + 1)) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Renders a bitmap as braille chars starting at given position (left, top).
+// Blank chars (no dots set) are skipped.
+fn render_braille(
+    bitmap: &[u8],
+    width: usize,
+    position: (u16, u16),
+    modifier: Modifier,
+    buf: &mut Buffer,
+) {
+    render_chars(
+        &to_braille(bitmap, width),
+        '\u{2800}',
+        position,
+        modifier,
+        buf,
+    );
+}
+
+// Renders a bitmap as half blocks starting at given position (left, top).
+// Blank chars are skipped.
+fn render_half_blocks(
+    bitmap: &[u8],
+    width: usize,
+    position: (u16, u16),
+    modifier: Modifier,
+    buf: &mut Buffer,
+) {
+    render_chars(&to_half_blocks(bitmap, width), ' ', position, modifier, buf);
+}
+
+fn render_chars(
+    rows: &[Vec<char>],
+    blank: char,
+    (left, top): (u16, u16),
+    modifier: Modifier,
+    buf: &mut Buffer,
+) {
+    for (y, row) in rows.iter().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            if *c == blank {
+                continue;
+            }
+            let p = Position {
+                x: left + x as u16,
+                y: top + y as u16,
+            };
+            if let Some(cell) = buf.cell_mut(p) {
+                cell.set_char(*c);
+                cell.modifier.insert(modifier);
+            }
+        }
+    }
+}
+
+/// Height of a digit (incl. border row) drawn by given font and `Digit::vertical_scale`
+pub fn get_digit_height(font: DigitFont, vertical_scale: u16) -> u16 {
+    match font {
+        // half blocks are not scaled
+        DigitFont::HalfHeight => HALF_DIGIT_HEIGHT,
+        // border row is not scaled
+        DigitFont::Block => DIGIT_HEIGHT + DIGIT_SIZE as u16 * (vertical_scale.max(1) - 1),
+    }
+}
+
+// Shade of a bitmap row (`0` = top) of `Style::Gradient`
+fn get_gradient_symbol(row: usize) -> &'static str {
+    const SHADES: [&str; 4] = [shade::LIGHT, shade::MEDIUM, shade::DARK, shade::FULL];
+    SHADES[row * (SHADES.len() - 1) / (DIGIT_SIZE - 1)]
+}
+
 pub struct Digit<'a> {
     digit: u64,
     with_border: bool,
     symbol: &'a str,
+    vertical_scale: u16,
+    braille: bool,
+    half_height: bool,
+    gradient: bool,
+    emphasize: bool,
 }
 
 impl<'a> Digit<'a> {
@@ -121,8 +268,47 @@ impl<'a> Digit<'a> {
             digit,
             with_border,
             symbol,
+            vertical_scale: 1,
+            braille: false,
+            half_height: false,
+            gradient: false,
+            emphasize: false,
         }
     }
+
+    /// Renders the digit bold (e.g. to mark a change)
+    pub fn emphasize(mut self, emphasize: bool) -> Self {
+        self.emphasize = emphasize;
+        self
+    }
+
+    /// Fills rows from light (top) to full (bottom) shade instead of `symbol` (see `Style::Gradient`)
+    pub fn gradient(mut self, gradient: bool) -> Self {
+        self.gradient = gradient;
+        self
+    }
+
+    /// Number of terminal rows to draw each bitmap row with
+    pub fn vertical_scale(mut self, scale: u16) -> Self {
+        self.vertical_scale = scale.max(1);
+        self
+    }
+
+    /// Draws the digit by braille dots (see `Style::Dots`).
+    /// It needs 3x2 cells only, which are placed in the middle of the digit area.
+    /// `symbol` and `vertical_scale` are ignored.
+    pub fn braille(mut self, braille: bool) -> Self {
+        self.braille = braille;
+        self
+    }
+
+    /// Draws the digit by half blocks (see `DigitFont::HalfHeight`).
+    /// It needs 5x3 cells only (see `HALF_DIGIT_HEIGHT`), which are placed at the top of the digit area.
+    /// `symbol` and `vertical_scale` are ignored.
+    pub fn half_height(mut self, half_height: bool) -> Self {
+        self.half_height = half_height;
+        self
+    }
 }
 
 impl Widget for Digit<'_> {
@@ -141,22 +327,32 @@ impl Widget for Digit<'_> {
             7 => DIGIT_7,
             8 => DIGIT_8,
             9 => DIGIT_9,
+            // Callers are expected to pass single digits only (`0..=9`).
+            // Anything else is a bug and rendered as an error (`E`) intentionally.
             _ => CHAR_E,
         };
 
-        patterns.iter().enumerate().for_each(|(i, item)| {
-            let x = i % DIGIT_SIZE;
-            let y = i / DIGIT_SIZE;
-            if *item == 1 {
-                let p = Position {
-                    x: left + x as u16,
-                    y: top + y as u16,
-                };
-                if let Some(cell) = buf.cell_mut(p) {
-                    cell.set_symbol(self.symbol);
-                }
-            }
-        });
+        let modifier = if self.emphasize {
+            Modifier::BOLD
+        } else {
+            Modifier::empty()
+        };
+        if self.braille {
+            render_braille(&patterns, DIGIT_SIZE, (left + 1, top + 1), modifier, buf);
+        } else if self.half_height {
+            render_half_blocks(&patterns, DIGIT_SIZE, (left, top), modifier, buf);
+        } else {
+            self.render_pattern(&patterns, modifier, left, top, buf);
+        }
+
+        // error glyph in red to make it easy to spot
+        if patterns == CHAR_E {
+            let digit_area = Rect {
+                height: area.height.saturating_sub(1), /* w/o border */
+                ..area
+            };
+            buf.set_style(digit_area.intersection(buf.area), Color::Red);
+        }
 
         // Add border at the bottom
         if self.with_border {
@@ -173,32 +369,117 @@ impl Widget for Digit<'_> {
     }
 }
 
+impl Digit<'_> {
+    fn render_pattern(
+        &self,
+        patterns: &[u8],
+        modifier: Modifier,
+        left: u16,
+        top: u16,
+        buf: &mut Buffer,
+    ) {
+        patterns.iter().enumerate().for_each(|(i, item)| {
+            let x = i % DIGIT_SIZE;
+            let y = i / DIGIT_SIZE;
+            let symbol = if self.gradient {
+                get_gradient_symbol(y)
+            } else {
+                self.symbol
+            };
+            if *item == 1 {
+                for row in 0..self.vertical_scale {
+                    let p = Position {
+                        x: left + x as u16,
+                        y: top + y as u16 * self.vertical_scale + row,
+                    };
+                    if let Some(cell) = buf.cell_mut(p) {
+                        cell.set_symbol(symbol);
+                        cell.modifier.insert(modifier);
+                    }
+                }
+            }
+        });
+    }
+}
+
 pub struct Dot<'a> {
     symbol: &'a str,
+    vertical_scale: u16,
+    braille: bool,
+    half_height: bool,
 }
 
 impl<'a> Dot<'a> {
     pub fn new(symbol: &'a str) -> Self {
-        Self { symbol }
+        Self {
+            symbol,
+            vertical_scale: 1,
+            braille: false,
+            half_height: false,
+        }
+    }
+
+    /// Number of terminal rows to draw the dot with
+    pub fn vertical_scale(mut self, scale: u16) -> Self {
+        self.vertical_scale = scale.max(1);
+        self
+    }
+
+    /// Draws the dot by braille dots to match `Digit::braille`
+    pub fn braille(mut self, braille: bool) -> Self {
+        self.braille = braille;
+        self
+    }
+
+    /// Draws the dot by half blocks to match `Digit::half_height`
+    pub fn half_height(mut self, half_height: bool) -> Self {
+        self.half_height = half_height;
+        self
     }
 }
 
 impl Widget for Dot<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let positions = [
-            Position {
-                x: area.left() + 1,
-                y: area.top() + area.height - 2,
-            },
-            Position {
-                x: area.left() + 2,
-                y: area.top() + area.height - 2,
-            },
-        ];
-
-        for pos in positions {
-            if let Some(cell) = buf.cell_mut(pos) {
-                cell.set_symbol(self.symbol);
+        let left = area.left();
+        if self.braille {
+            render_braille(
+                &DOT_BITMAP,
+                2,
+                (left + 1, area.top() + 1),
+                Modifier::empty(),
+                buf,
+            );
+            return;
+        }
+        if self.half_height {
+            render_half_blocks(
+                &DOT_BITMAP,
+                2,
+                (left + 1, area.top()),
+                Modifier::empty(),
+                buf,
+            );
+            return;
+        }
+        // same row as the last row of a digit
+        let top = area.top() + (DIGIT_SIZE as u16 - 1) * self.vertical_scale;
+
+        for row in 0..self.vertical_scale {
+            let positions = [
+                Position {
+                    x: left + 1,
+                    y: top + row,
+                },
+                Position {
+                    x: left + 2,
+                    y: top + row,
+                },
+            ];
+
+            for pos in positions {
+                if let Some(cell) = buf.cell_mut(pos) {
+                    cell.set_symbol(self.symbol);
+                }
             }
         }
     }
@@ -206,41 +487,74 @@ impl Widget for Dot<'_> {
 
 pub struct Colon<'a> {
     symbol: &'a str,
+    vertical_scale: u16,
+    braille: bool,
+    half_height: bool,
 }
 
 impl<'a> Colon<'a> {
     pub fn new(symbol: &'a str) -> Self {
-        Self { symbol }
+        Self {
+            symbol,
+            vertical_scale: 1,
+            braille: false,
+            half_height: false,
+        }
+    }
+
+    /// Number of terminal rows to draw each dot of the colon with
+    pub fn vertical_scale(mut self, scale: u16) -> Self {
+        self.vertical_scale = scale.max(1);
+        self
+    }
+
+    /// Draws the colon by braille dots to match `Digit::braille`
+    pub fn braille(mut self, braille: bool) -> Self {
+        self.braille = braille;
+        self
+    }
+
+    /// Draws the colon by half blocks to match `Digit::half_height`
+    pub fn half_height(mut self, half_height: bool) -> Self {
+        self.half_height = half_height;
+        self
     }
 }
 
 impl Widget for Colon<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let left = area.left();
+        // center dots (2 cells) horizontally
+        let left = area.left() + area.width.saturating_sub(2) / 2;
         let top = area.top();
 
-        let positions = [
-            Position {
-                x: left + 1,
-                y: top + 1,
-            },
-            Position {
-                x: left + 2,
-                y: top + 1,
-            },
-            Position {
-                x: left + 1,
-                y: top + 3,
-            },
-            Position {
-                x: left + 2,
-                y: top + 3,
-            },
-        ];
-
-        for pos in positions {
-            if let Some(cell) = buf.cell_mut(pos) {
-                cell.set_symbol(self.symbol);
+        if self.braille {
+            render_braille(&COLON_BITMAP, 2, (left, top + 1), Modifier::empty(), buf);
+            return;
+        }
+        if self.half_height {
+            render_half_blocks(&COLON_BITMAP, 2, (left, top), Modifier::empty(), buf);
+            return;
+        }
+
+        // dots are placed at 2nd and 4th row of a digit
+        for y in [1, 3] {
+            for row in 0..self.vertical_scale {
+                let positions = [
+                    Position {
+                        x: left,
+                        y: top + y * self.vertical_scale + row,
+                    },
+                    Position {
+                        x: left + 1,
+                        y: top + y * self.vertical_scale + row,
+                    },
+                ];
+
+                for pos in positions {
+                    if let Some(cell) = buf.cell_mut(pos) {
+                        cell.set_symbol(self.symbol);
+                    }
+                }
             }
         }
     }
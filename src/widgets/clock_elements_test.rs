@@ -1,7 +1,8 @@
 use crate::widgets::clock_elements::*;
-use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
 
 const D_RECT: Rect = Rect::new(0, 0, DIGIT_WIDTH, DIGIT_HEIGHT);
+const HALF_D_RECT: Rect = Rect::new(0, 0, DIGIT_WIDTH, HALF_DIGIT_HEIGHT);
 
 #[test]
 fn test_d1() {
@@ -90,3 +91,182 @@ fn test_colon() {
     ]);
     assert_eq!(b, expected);
 }
+
+#[test]
+fn test_d1_vertical_scale() {
+    let area = Rect::new(0, 0, DIGIT_WIDTH, DIGIT_SIZE as u16 * 2 + 1);
+    let mut b = Buffer::empty(area);
+    Digit::new(1, true, "█")
+        .vertical_scale(2)
+        .render(area, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "   ██",
+        "─────",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_colon_vertical_scale() {
+    let area = Rect::new(0, 0, COLON_WIDTH, DIGIT_SIZE as u16 * 2 + 1);
+    let mut b = Buffer::empty(area);
+    Colon::new("█").vertical_scale(2).render(area, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "    ",
+        "    ",
+        " ██ ",
+        " ██ ",
+        "    ",
+        "    ",
+        " ██ ",
+        " ██ ",
+        "    ",
+        "    ",
+        "    ",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_dot_vertical_scale() {
+    let area = Rect::new(0, 0, DOT_WIDTH, DIGIT_SIZE as u16 * 2 + 1);
+    let mut b = Buffer::empty(area);
+    Dot::new("█").vertical_scale(2).render(area, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "    ",
+        "    ",
+        "    ",
+        "    ",
+        "    ",
+        "    ",
+        "    ",
+        "    ",
+        " ██ ",
+        " ██ ",
+        "    ",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_error() {
+    let mut b = Buffer::empty(D_RECT);
+    Digit::new(10, false, "█").render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let mut expected = Buffer::with_lines([
+        "█████",
+        "██   ",
+        "████ ",
+        "██   ",
+        "█████",
+        "     ",
+    ]);
+    // highlighted by default
+    expected.set_style(Rect::new(0, 0, 5, 5), Color::Red);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_error_highlight() {
+    let mut b = Buffer::empty(D_RECT);
+    Digit::new(10, true, "█").render(D_RECT, &mut b);
+    // error cells
+    assert_eq!(b[(0, 0)].fg, Color::Red);
+    assert_eq!(b[(4, 4)].fg, Color::Red);
+    // border is not highlighted
+    assert_eq!(b[(0, 5)].fg, Color::Reset);
+
+    // valid digits are not highlighted
+    let mut b = Buffer::empty(D_RECT);
+    Digit::new(8, false, "█").render(D_RECT, &mut b);
+    assert_eq!(b[(0, 0)].fg, Color::Reset);
+}
+
+#[test]
+fn test_d8_braille() {
+    let mut b = Buffer::empty(D_RECT);
+    Digit::new(8, false, "█")
+        .braille(true)
+        .render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "     ",
+        " ⣿⢽⡇ ",
+        " ⠉⠉⠁ ",
+        "     ",
+        "     ",
+        "     ",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_colon_dot_braille() {
+    let mut b = Buffer::empty(D_RECT);
+    Colon::new("█").braille(true).render(D_RECT, &mut b);
+    Dot::new("█").braille(true).render(D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "     ",
+        " ⣒   ",
+        " ⠉   ",
+        "     ",
+        "     ",
+        "     ",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_d8_half_height() {
+    let mut b = Buffer::empty(HALF_D_RECT);
+    Digit::new(8, false, "█")
+        .half_height(true)
+        .render(HALF_D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "██▀██",
+        "██▀██",
+        "▀▀▀▀▀",
+        "     ",
+    ]);
+    assert_eq!(b, expected);
+}
+
+#[test]
+fn test_colon_dot_half_height() {
+    let mut b = Buffer::empty(HALF_D_RECT);
+    Colon::new("█")
+        .half_height(true)
+        .render(HALF_D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        " ▄▄  ",
+        " ▄▄  ",
+        "     ",
+        "     ",
+    ]);
+    assert_eq!(b, expected);
+    let mut b = Buffer::empty(HALF_D_RECT);
+    Dot::new("█").half_height(true).render(HALF_D_RECT, &mut b);
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "     ",
+        "     ",
+        " ▀▀  ",
+        "     ",
+    ]);
+    assert_eq!(b, expected);
+}
@@ -1,8 +1,12 @@
 use crate::{
-    common::Style,
-    duration::{ONE_DECI_SECOND, ONE_HOUR, ONE_MINUTE, ONE_SECOND},
+    common::{DigitFont, Style},
+    config::Settings,
+    duration::{DurationEx, MAX_DURATION, ONE_DECI_SECOND, ONE_HOUR, ONE_MINUTE, ONE_SECOND},
     widgets::clock::*,
 };
+use ratatui::{
+    buffer::Buffer, layout::Rect, style::Modifier, symbols::shade, widgets::StatefulWidget,
+};
 use std::time::Duration;
 
 #[test]
@@ -417,3 +421,1500 @@ fn test_edit_down_hhmmss() {
     c.edit_down();
     assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
 }
+
+#[test]
+fn test_reset_to_defaults() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    // session changes
+    c.style = Style::Cross;
+    c.with_decis = true;
+
+    c.reset_to_defaults(&Settings {
+        style: Style::Braille,
+        with_decis: false,
+        ..Settings::default()
+    });
+    assert_eq!(c.style, Style::Braille);
+    assert!(!c.with_decis);
+    assert_eq!(c.get_current_value(), c.get_initial_value());
+    assert_eq!(c.get_mode(), &Mode::Initial);
+}
+
+#[test]
+fn test_reset_keeps_style() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: false,
+    });
+    c.style = Style::Cross;
+    c.with_decis = true;
+    c.reset();
+    // value-only
+    assert_eq!(c.style, Style::Cross);
+    assert!(c.with_decis);
+    assert_eq!(c.get_current_value(), c.get_initial_value());
+}
+
+#[test]
+fn test_get_height_vertical_scale() {
+    let w = ClockWidget::<Timer>::new();
+    assert_eq!(w.get_height(), 6);
+    let w = ClockWidget::<Timer>::new().vertical_scale(2);
+    // 10 rows of digits + 1 border
+    assert_eq!(w.get_height(), 11);
+}
+
+#[test]
+fn test_finish_stop() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND,
+        current_value: ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    assert!(c.is_done());
+}
+
+#[test]
+fn test_finish_repeat() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 2,
+        current_value: ONE_SECOND * 2,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_on_finish(FinishAction::Repeat);
+    c.toggle_pause();
+    for _ in 0..4 {
+        c.tick();
+    }
+    assert!(c.is_running());
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 2);
+}
+
+#[test]
+fn test_finish_next_preset() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_on_finish(FinishAction::NextPreset);
+    c.set_presets(vec![ONE_SECOND * 3, ONE_SECOND * 2, ONE_SECOND]);
+    // reset to first preset
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND * 3);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 3);
+
+    c.toggle_pause();
+    for _ in 0..3 {
+        c.tick();
+    }
+    // 2nd preset
+    assert!(c.is_running());
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND * 2);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 2);
+
+    for _ in 0..2 {
+        c.tick();
+    }
+    // 3rd preset
+    assert!(c.is_running());
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND);
+
+    c.tick();
+    // no more presets
+    assert!(c.is_done());
+
+    // reset starts over with 1st preset
+    c.reset();
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND * 3);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 3);
+    c.toggle_pause();
+    for _ in 0..3 {
+        c.tick();
+    }
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND * 2);
+}
+
+#[test]
+fn test_apply_delta_secs_countdown() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    // positive
+    c.apply_delta_secs(10);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_secs(70)
+    );
+    // raises initial value
+    assert_eq!(
+        Duration::from(*c.get_initial_value()),
+        Duration::from_secs(70)
+    );
+    // negative
+    c.apply_delta_secs(-20);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_secs(50)
+    );
+    assert!(!c.is_done());
+    // negative into floor
+    c.apply_delta_secs(-100);
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    assert!(c.is_done());
+}
+
+#[test]
+fn test_apply_delta_secs_countdown_cap() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_HOUR * 99,
+        current_value: ONE_HOUR * 99,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    // positive into cap
+    c.apply_delta_secs(i64::MAX);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_secs(100 * 60 * 60 - 1)
+    );
+    assert_eq!(c.get_format(), Format::HhMmSs);
+}
+
+#[test]
+fn test_apply_delta_secs_timer() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    // positive
+    c.apply_delta_secs(10);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_secs(70)
+    );
+    // negative into floor
+    c.apply_delta_secs(-100);
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    assert!(!c.is_done());
+    // positive into cap
+    c.apply_delta_secs(100 * 60 * 60);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_secs(100 * 60 * 60 - 1)
+    );
+    assert!(c.is_done());
+    // negative out of cap
+    c.apply_delta_secs(-60);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_secs(100 * 60 * 60 - 61)
+    );
+    assert_eq!(c.get_mode(), &Mode::Pause);
+}
+
+#[test]
+fn test_apply_delta_secs_countdown_repeat() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 10,
+        current_value: ONE_SECOND * 10,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_on_finish(FinishAction::Repeat);
+    c.toggle_pause();
+    // done, but not repeated
+    c.apply_delta_secs(-100);
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    assert!(c.is_done());
+    assert!(!c.just_finished());
+}
+
+#[test]
+fn test_apply_delta_secs_countdown_done() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert!(c.is_done());
+    c.apply_delta_secs(30);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 30);
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    // runs again
+    c.toggle_pause();
+    c.tick();
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 29);
+}
+
+#[test]
+fn test_get_format_decis_boundary() {
+    let format = |value: Duration, with_decis: bool| {
+        Clock::<Countdown>::new(ClockArgs {
+            initial_value: value,
+            current_value: value,
+            tick_value: ONE_DECI_SECOND,
+            style: Style::default(),
+            with_decis,
+        })
+        .get_format()
+    };
+    // `with_decis` does not change the format: below 10s it's always the tightest one (`S`)
+    for with_decis in [false, true] {
+        assert_eq!(format(ONE_SECOND * 10, with_decis), Format::Ss);
+        assert_eq!(
+            format(ONE_SECOND * 10 - ONE_DECI_SECOND, with_decis),
+            Format::S
+        );
+        assert_eq!(format(ONE_SECOND * 9, with_decis), Format::S);
+        assert_eq!(format(ONE_DECI_SECOND, with_decis), Format::S);
+    }
+}
+
+#[test]
+fn test_edit_running_clock() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    c.toggle_edit();
+    // edit seconds
+    c.edit_prev();
+    c.edit_down();
+    let edited = *c.get_current_value();
+    assert_eq!(Duration::from(edited), ONE_MINUTE - ONE_SECOND * 2);
+    // no ticks while editing
+    for _ in 0..5 {
+        c.tick();
+    }
+    assert_eq!(*c.get_current_value(), edited);
+    // exit edit mode
+    c.toggle_edit();
+    assert!(c.is_running());
+    c.tick();
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from(edited) - ONE_SECOND
+    );
+}
+
+#[test]
+fn test_render_to_string() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_MINUTE + ONE_SECOND * 2,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: false,
+    });
+    let widget = ClockWidget::new();
+    let width = widget.get_width(&c.get_format(), false);
+    let height = widget.get_height();
+    let actual = widget.render_to_string(&mut c, width, height);
+    #[rustfmt::skip]
+    let expected = [
+        "   ██    █████ █████",
+        "   ██ ██ ██ ██    ██",
+        "   ██    ██ ██ █████",
+        "   ██ ██ ██ ██ ██   ",
+        "   ██    █████ █████",
+        "                    ",
+    ].join("\n");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_rewind_timer() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.rewind(ONE_SECOND * 10);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 50);
+    assert_eq!(c.get_format(), Format::Ss);
+    assert_eq!(c.get_mode(), &Mode::Tick);
+    // min. 0
+    c.rewind(ONE_MINUTE);
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    assert_eq!(c.get_mode(), &Mode::Tick);
+}
+
+#[test]
+fn test_rewind_countdown() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_SECOND * 30,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    c.rewind(ONE_SECOND * 10);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 40);
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    // max. `initial_value`
+    c.rewind(ONE_HOUR);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_MINUTE);
+    assert_eq!(c.get_mode(), &Mode::Pause);
+}
+
+#[test]
+fn test_rewind_done() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert!(c.is_done());
+    c.rewind(ONE_SECOND * 10);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 10);
+    assert_eq!(c.get_mode(), &Mode::Pause);
+
+    let mut t = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: MAX_DURATION,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert!(t.is_done());
+    t.rewind(ONE_SECOND * 10);
+    assert_eq!(
+        Duration::from(*t.get_current_value()),
+        MAX_DURATION - ONE_SECOND * 10
+    );
+    assert_eq!(t.get_mode(), &Mode::Pause);
+}
+
+#[test]
+fn test_just_finished() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND,
+        current_value: ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    assert!(!c.just_finished());
+    c.tick();
+    assert!(c.is_done());
+    // once per finish only
+    assert!(c.just_finished());
+    assert!(!c.just_finished());
+    c.tick();
+    assert!(!c.just_finished());
+    // next finish
+    c.reset();
+    c.toggle_pause();
+    c.tick();
+    assert!(c.just_finished());
+}
+
+#[test]
+fn test_checkpoints() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND * 5,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_checkpoints(vec![ONE_SECOND * 30, ONE_SECOND * 10]);
+    // paused
+    assert_eq!(c.tick(), None);
+    c.toggle_pause();
+    // 55s
+    assert_eq!(c.tick(), None);
+    c.rewind(ONE_SECOND * 5);
+    // 60s -> 30s
+    for _ in 0..5 {
+        assert_eq!(c.tick(), None);
+    }
+    assert_eq!(c.tick(), Some(ONE_SECOND * 30));
+    // 30s -> 10s
+    for _ in 0..3 {
+        assert_eq!(c.tick(), None);
+    }
+    assert_eq!(c.tick(), Some(ONE_SECOND * 10));
+    // fired once
+    c.rewind(ONE_SECOND * 5);
+    assert_eq!(c.tick(), None);
+}
+
+#[test]
+fn test_checkpoints_coarse_tick() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND * 45,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_checkpoints(vec![ONE_SECOND * 30, ONE_SECOND * 20, ONE_SECOND * 10]);
+    c.toggle_pause();
+    // 60s -> 15s: smallest crossed checkpoint
+    assert_eq!(c.tick(), Some(ONE_SECOND * 20));
+    // 15s -> 0s: 30s and 20s have been fired already
+    assert_eq!(c.tick(), Some(ONE_SECOND * 10));
+    assert!(c.is_done());
+    // re-armed by reset
+    c.reset();
+    c.toggle_pause();
+    assert_eq!(c.tick(), Some(ONE_SECOND * 20));
+}
+
+#[test]
+fn test_retick_done_countdown() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND,
+        current_value: ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    assert!(c.is_done());
+    assert!(c.just_finished());
+    // force `Tick`
+    for _ in 0..3 {
+        c.toggle_pause();
+        assert!(c.is_running());
+        c.tick();
+        assert!(c.is_done());
+        assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    }
+    // no other finish
+    assert!(!c.just_finished());
+}
+
+#[test]
+fn test_retick_done_timer() {
+    let max = Duration::from_secs(100 * 60 * 60) - ONE_SECOND;
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: max - ONE_DECI_SECOND * 5,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    // clamped
+    assert_eq!(Duration::from(*c.get_current_value()), max);
+    assert!(c.is_done());
+    // force `Tick`
+    for _ in 0..3 {
+        c.toggle_pause();
+        assert!(c.is_running());
+        c.tick();
+        assert!(c.is_done());
+        assert_eq!(Duration::from(*c.get_current_value()), max);
+    }
+}
+
+#[test]
+fn test_hybrid_clock_widget() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_HOUR + ONE_MINUTE * 2 + ONE_SECOND * 3,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: true,
+    });
+    let widget = ClockWidget::<Timer>::new().mono_digit(true);
+    // h(1) + colon(4) + mm(2) + colon(4) + ss.d(4)
+    assert_eq!(widget.get_width(&c.get_format(), true), 15);
+    assert_eq!(widget.get_height(), 5);
+    let actual = widget.render_to_string(&mut c, 15, 5);
+    #[rustfmt::skip]
+    let expected = [
+        "               ",
+        "  ██    ██     ",
+        "1    02    03.0",
+        "  ██    ██     ",
+        "               ",
+    ].join("\n");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_hybrid_clock_widget_edit() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_MINUTE * 12 + ONE_SECOND * 34,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: false,
+    });
+    c.toggle_edit();
+    let widget = HybridClockWidget::<Timer>::new();
+    let area = Rect::new(0, 0, widget.get_width(&c.get_format(), false), 5);
+    let mut b = Buffer::empty(area);
+    widget.render(area, &mut b, &mut c);
+    assert_eq!(b[(0, 2)].symbol(), "1");
+    // minutes are edited
+    assert!(b[(0, 2)].modifier.contains(Modifier::UNDERLINED));
+    assert!(!b[(6, 2)].modifier.contains(Modifier::UNDERLINED));
+}
+
+#[test]
+fn test_new_clamps_to_max() {
+    let max = Duration::from_secs(100 * 60 * 60) - ONE_SECOND;
+    let args = || ClockArgs {
+        initial_value: ONE_HOUR * 200,
+        current_value: ONE_HOUR * 200,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: false,
+    };
+    let mut countdown = Clock::<Countdown>::new(args());
+    assert_eq!(Duration::from(*countdown.get_initial_value()), max);
+    assert_eq!(Duration::from(*countdown.get_current_value()), max);
+    assert_eq!(countdown.get_current_value().to_string(), "99:59:59");
+
+    let timer = Clock::<Timer>::new(args());
+    assert_eq!(Duration::from(*timer.get_current_value()), max);
+
+    // valid digits: `99:59:59`
+    let widget = ClockWidget::<Countdown>::new();
+    let width = widget.get_width(&countdown.get_format(), false);
+    let rendered = widget.render_to_string(&mut countdown, width, 6);
+    #[rustfmt::skip]
+    let expected = "█████ █████    █████ █████    █████ █████";
+    assert_eq!(rendered.lines().next(), Some(expected));
+    assert!(rendered.lines().nth(1).unwrap().starts_with("██ ██ ██ ██"));
+}
+
+#[test]
+fn test_max_visible_field() {
+    assert_eq!(Format::HhMmSs.max_visible_field(), Time::Hours);
+    assert_eq!(Format::HMmSs.max_visible_field(), Time::Hours);
+    assert_eq!(Format::MmSs.max_visible_field(), Time::Minutes);
+    assert_eq!(Format::MSs.max_visible_field(), Time::Minutes);
+    assert_eq!(Format::Ss.max_visible_field(), Time::Seconds);
+    assert_eq!(Format::S.max_visible_field(), Time::Seconds);
+}
+
+#[test]
+fn test_snooze() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND,
+        current_value: ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    // not done -> ignored
+    c.snooze(ONE_SECOND * 2);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND);
+
+    c.toggle_pause();
+    c.tick();
+    assert!(c.is_done());
+    c.snooze(ONE_SECOND * 2);
+    assert!(c.is_running());
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 2);
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND * 3);
+
+    // runs down again
+    c.tick();
+    c.tick();
+    assert!(c.is_done());
+}
+
+#[test]
+fn test_countdown_from_str() {
+    let c: Clock<Countdown> = "25:00".parse().unwrap();
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_MINUTE * 25);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_MINUTE * 25);
+    assert_eq!(c.get_format(), Format::MmSs);
+    // defaults
+    assert_eq!(c.style, Style::default());
+    assert!(!c.with_decis);
+    assert_eq!(*c.get_mode(), Mode::Initial);
+
+    let c: Clock<Countdown> = "1:02:03".parse().unwrap();
+    assert_eq!(c.get_current_value().to_string(), "1:02:03");
+
+    // errors
+    assert!("".parse::<Clock<Countdown>>().is_err());
+    assert!("5:60".parse::<Clock<Countdown>>().is_err());
+    assert!("abc".parse::<Clock<Countdown>>().is_err());
+    assert!("1:2:3:4".parse::<Clock<Countdown>>().is_err());
+}
+
+#[test]
+fn test_narrow_colon() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: ONE_MINUTE * 12 + ONE_SECOND * 34,
+        current_value: ONE_MINUTE * 12 + ONE_SECOND * 34,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    let widget = ClockWidget::<Timer>::new;
+    let width = widget().get_width(&c.get_format(), false);
+    assert_eq!(width, 26);
+    // one column too narrow
+    let rendered = widget().render_to_string(&mut c, width - 1, 6);
+    // nothing clipped
+    #[rustfmt::skip]
+    let expected = [
+        "    ██ █████  █████ ██ ██",
+        "    ██    ████   ██ ██ ██",
+        "    ██ █████  █████ █████",
+        "    ██ ██   ██   ██    ██",
+        "    ██ █████  █████    ██",
+    ];
+    for (row, expected) in rendered.lines().zip(expected) {
+        assert_eq!(row, expected);
+    }
+}
+
+#[test]
+fn test_active_elapsed() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    let span = Duration::from_millis(20);
+    // not started
+    std::thread::sleep(span);
+    assert_eq!(c.active_elapsed(), Duration::ZERO);
+    // running
+    c.toggle_pause();
+    std::thread::sleep(span);
+    assert!(c.active_elapsed() >= span);
+    // paused
+    c.toggle_pause();
+    let active = c.active_elapsed();
+    std::thread::sleep(span);
+    assert_eq!(c.active_elapsed(), active);
+    // running again
+    c.toggle_pause();
+    std::thread::sleep(span);
+    assert!(c.active_elapsed() >= active + span);
+    assert!(c.wall_elapsed() >= span * 4);
+    assert!(c.active_elapsed() < c.wall_elapsed());
+}
+
+#[test]
+fn test_active_elapsed_done() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 10,
+        current_value: ONE_SECOND * 10,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    let span = Duration::from_millis(20);
+    c.toggle_pause();
+    std::thread::sleep(span);
+    // done w/o any tick
+    c.apply_delta_secs(-100);
+    assert!(c.is_done());
+    let active = c.active_elapsed();
+    std::thread::sleep(span);
+    assert_eq!(c.active_elapsed(), active);
+}
+
+#[test]
+fn test_last_changed() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE + ONE_SECOND,
+        current_value: ONE_MINUTE + ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert_eq!(c.last_changed(), None);
+    c.toggle_pause();
+    // 1:01 -> 1:00
+    c.tick();
+    assert_eq!(c.last_changed(), Some(Time::Seconds));
+    // 1:00 -> 0:59
+    c.tick();
+    assert_eq!(c.last_changed(), Some(Time::Minutes));
+    c.reset();
+    assert_eq!(c.last_changed(), None);
+
+    // emphasized until next tick
+    c.toggle_pause();
+    c.tick();
+    let widget = || ClockWidget::<Countdown>::new().emphasize_changes(true);
+    let area = Rect::new(0, 0, 17, 6);
+    for _ in 0..2 {
+        // rendered by a clone (like `App` does)
+        let mut buf = Buffer::empty(area);
+        widget().render(area, &mut buf, &mut c.clone());
+        // `1:00`: minutes are not bold, but seconds are
+        assert!(!buf[(0, 0)].modifier.contains(Modifier::BOLD));
+        assert!(buf[(16, 0)].modifier.contains(Modifier::BOLD));
+    }
+    // a tick of a paused clock clears it
+    c.toggle_pause();
+    c.tick();
+    assert_eq!(c.last_changed(), None);
+    let mut buf = Buffer::empty(area);
+    widget().render(area, &mut buf, &mut c.clone());
+    assert!(!buf[(16, 0)].modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn test_background_is_dark() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::Auto,
+        with_decis: false,
+    });
+    let symbols = |widget: ClockWidget<Timer>, c: &mut Clock<Timer>| {
+        let area = Rect::new(0, 0, 20, 6);
+        let mut b = Buffer::empty(area);
+        widget.render(area, &mut b, c);
+        b.content()
+            .iter()
+            .map(|cell| cell.symbol().to_string())
+            .filter(|s| s.trim() != "")
+            .collect::<std::collections::HashSet<_>>()
+    };
+    for mono_digit in [false, true] {
+        let widget = || ClockWidget::new().mono_digit(mono_digit);
+        let dark = symbols(widget(), &mut c);
+        assert!(dark.contains(shade::DARK), "{}", mono_digit);
+        assert!(!dark.contains(shade::LIGHT), "{}", mono_digit);
+        let light = symbols(widget().background_is_dark(false), &mut c);
+        assert!(light.contains(shade::LIGHT), "{}", mono_digit);
+        assert!(!light.contains(shade::DARK), "{}", mono_digit);
+    }
+}
+
+#[test]
+fn test_font() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_SECOND,
+        tick_value: ONE_DECI_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    let render = |font: DigitFont, c: &mut Clock<Timer>| {
+        let widget = ClockWidget::<Timer>::new().font(font);
+        let height = widget.get_height();
+        widget.render_to_string(c, 5, height)
+    };
+    // `half-height`: 5 rows of a digit in 3 rows + border row
+    #[rustfmt::skip]
+    let expected = [
+        "   ██",
+        "   ██",
+        "   ▀▀",
+        "     ",
+    ].join("\n");
+    assert_eq!(render(DigitFont::HalfHeight, &mut c), expected);
+}
+
+#[test]
+fn test_font_height() {
+    let widget =
+        |font: DigitFont, scale: u16| ClockWidget::<Timer>::new().font(font).vertical_scale(scale);
+    assert_eq!(widget(DigitFont::Block, 1).get_height(), 6);
+    assert_eq!(widget(DigitFont::Block, 2).get_height(), 11);
+    // half blocks are not scaled
+    assert_eq!(widget(DigitFont::HalfHeight, 1).get_height(), 4);
+    assert_eq!(widget(DigitFont::HalfHeight, 2).get_height(), 4);
+}
+
+#[test]
+fn test_edit_paused_countdown_to_zero() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    c.toggle_pause();
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    // 0:59 -> 0:00
+    c.toggle_edit();
+    for _ in 0..59 {
+        c.edit_down();
+    }
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    // still editable while editing
+    assert!(matches!(c.get_mode(), Mode::Editable(_, _)));
+    c.toggle_edit();
+    assert_eq!(c.get_mode(), &Mode::Done);
+    assert!(c.is_done());
+    // not running while done
+    c.tick();
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+}
+
+#[test]
+fn test_edit_paused_countdown_to_zero_by_minutes() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE * 10,
+        current_value: ONE_MINUTE * 2,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    c.toggle_edit();
+    assert_eq!(
+        c.get_mode(),
+        &Mode::Editable(Time::Minutes, Box::new(Mode::Pause))
+    );
+    c.edit_down();
+    c.edit_down();
+    assert_eq!(Duration::from(*c.get_current_value()), Duration::ZERO);
+    c.toggle_edit();
+    assert_eq!(c.get_mode(), &Mode::Done);
+}
+
+#[test]
+fn test_tiny_area() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: true,
+    });
+    for (width, height) in [(2, 2), (1, 1), (4, 10), (20, 3)] {
+        let area = Rect::new(0, 0, width, height);
+        let mut b = Buffer::empty(area);
+        ClockWidget::new().render(area, &mut b, &mut c);
+        let drawn: Vec<&str> = b
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .filter(|s| !s.trim().is_empty())
+            .collect();
+        assert_eq!(drawn, vec!["⏱"], "{}x{}", width, height);
+    }
+}
+
+#[test]
+fn test_tiny_area_scaled() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 5,
+        current_value: ONE_SECOND * 5,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    let is_tiny = |widget: ClockWidget<Countdown>, height: u16, c: &mut Clock<Countdown>| {
+        let area = Rect::new(0, 0, 20, height);
+        let mut b = Buffer::empty(area);
+        widget.render(area, &mut b, c);
+        b.content().iter().any(|cell| cell.symbol() == "⏱")
+    };
+    // 5 rows of digits + border row
+    assert!(is_tiny(ClockWidget::new(), 5, &mut c));
+    assert!(!is_tiny(ClockWidget::new(), 6, &mut c));
+    // 2 * 5 rows of digits + border row
+    assert!(is_tiny(ClockWidget::new().vertical_scale(2), 10, &mut c));
+    assert!(!is_tiny(ClockWidget::new().vertical_scale(2), 11, &mut c));
+}
+
+#[test]
+fn test_toggle_edit_round_trip_countdown() {
+    let countdown = |current_value: Duration| {
+        Clock::<Countdown>::new(ClockArgs {
+            initial_value: ONE_MINUTE * 10,
+            current_value,
+            tick_value: ONE_SECOND,
+            style: Style::default(),
+            with_decis: false,
+        })
+    };
+    let mut initial = countdown(ONE_MINUTE * 10);
+    let mut pause = countdown(ONE_MINUTE * 5);
+    let mut tick = countdown(ONE_MINUTE * 5);
+    tick.toggle_pause();
+    let mut tick_hours = countdown(ONE_HOUR * 2);
+    tick_hours.toggle_pause();
+    let mut done = countdown(Duration::ZERO);
+    let mut pause_decis = countdown(Duration::from_millis(5500));
+    for (c, expected) in [
+        (&mut initial, Mode::Initial),
+        (&mut pause, Mode::Pause),
+        (&mut tick, Mode::Tick),
+        (&mut tick_hours, Mode::Tick),
+        (&mut done, Mode::Done),
+        (&mut pause_decis, Mode::Pause),
+    ] {
+        assert_eq!(c.get_mode(), &expected);
+        let value = *c.get_current_value();
+        c.toggle_edit();
+        assert!(c.is_edit_mode());
+        c.toggle_edit();
+        assert_eq!(c.get_mode(), &expected, "{}", value);
+        assert_eq!(c.get_current_value(), &value);
+    }
+}
+
+#[test]
+fn test_toggle_edit_round_trip_timer() {
+    let timer = |current_value: Duration| {
+        Clock::<Timer>::new(ClockArgs {
+            initial_value: Duration::ZERO,
+            current_value,
+            tick_value: ONE_SECOND,
+            style: Style::default(),
+            with_decis: false,
+        })
+    };
+    let mut initial = timer(Duration::ZERO);
+    let mut pause = timer(ONE_MINUTE);
+    let mut tick = timer(ONE_MINUTE);
+    tick.toggle_pause();
+    // started, but not ticked yet
+    let mut tick_zero = timer(Duration::ZERO);
+    tick_zero.toggle_pause();
+    let mut done = timer(MAX_DURATION);
+    for (c, expected) in [
+        (&mut initial, Mode::Initial),
+        (&mut pause, Mode::Pause),
+        (&mut tick, Mode::Tick),
+        (&mut tick_zero, Mode::Tick),
+        (&mut done, Mode::Done),
+    ] {
+        assert_eq!(c.get_mode(), &expected);
+        let value = *c.get_current_value();
+        c.toggle_edit();
+        assert!(c.is_edit_mode());
+        c.toggle_edit();
+        assert_eq!(c.get_mode(), &expected, "{}", value);
+        assert_eq!(c.get_current_value(), &value);
+    }
+}
+
+#[test]
+fn test_toggle_edit_special_cases_timer() {
+    // edited to max. -> `Done`
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: MAX_DURATION - ONE_SECOND,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_edit();
+    c.edit_prev();
+    assert_eq!(
+        c.get_mode(),
+        &Mode::Editable(Time::Seconds, Box::new(Mode::Pause))
+    );
+    c.edit_up();
+    assert_eq!(Duration::from(*c.get_current_value()), MAX_DURATION);
+    c.toggle_edit();
+    assert_eq!(c.get_mode(), &Mode::Done);
+    // edited below max. -> `Initial`
+    c.toggle_edit();
+    c.edit_down();
+    c.toggle_edit();
+    assert_eq!(c.get_mode(), &Mode::Initial);
+}
+
+#[test]
+fn test_fixed_format() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.reset_to_defaults(&Settings {
+        fixed_format: Some(Format::HhMmSs),
+        ..Settings::default()
+    });
+    c.toggle_pause();
+    for _ in 0..5 {
+        c.tick();
+    }
+    assert_eq!(c.get_format(), Format::HhMmSs);
+
+    // same width as `10:00:00`
+    let widget = ClockWidget::<Timer>::new();
+    let width = widget.get_width(&c.get_format(), c.with_decis);
+    assert_eq!(width, widget.get_width(&Format::HhMmSs, false));
+    let height = widget.get_height();
+    let actual = widget.render_to_string(&mut c, width, height);
+    // zero-padded `00:00:05`
+    assert_eq!(
+        actual.lines().next().unwrap(),
+        "█████ █████    █████ █████    █████ █████"
+    );
+
+    // all fields can be edited
+    c.toggle_pause();
+    c.toggle_edit();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Minutes, _)));
+    c.edit_next();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Hours, _)));
+    c.edit_up();
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_SECOND * 5
+    );
+}
+
+#[test]
+fn test_fixed_format_mono_digit() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: ONE_SECOND * 5,
+        tick_value: ONE_SECOND,
+        style: Style::Full,
+        with_decis: false,
+    });
+    c.set_fixed_format(Some(Format::HhMmSs));
+    let widget = ClockWidget::<Timer>::new().mono_digit(true);
+    // hh(2) + colon(4) + mm(2) + colon(4) + ss(2)
+    let width = widget.get_width(&c.get_format(), false);
+    assert_eq!(width, 14);
+    let height = widget.get_height();
+    let actual = widget.render_to_string(&mut c, width, height);
+    // zero-padded `00:00:05` instead of `5`
+    #[rustfmt::skip]
+    let expected = [
+        "              ",
+        "   ██    ██   ",
+        "00    00    05",
+        "   ██    ██   ",
+        "              ",
+    ].join("\n");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_fixed_format_too_small() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_HOUR,
+        current_value: ONE_HOUR,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_fixed_format(Some(Format::MmSs));
+    // hours are not cut off
+    assert_eq!(c.get_format(), Format::HMmSs);
+    c.toggle_pause();
+    c.tick();
+    assert_eq!(c.get_format(), Format::MmSs);
+    c.set_fixed_format(None);
+    assert_eq!(c.get_format(), Format::MmSs);
+}
+
+#[test]
+fn test_from_config() {
+    let settings = Settings {
+        style: Style::Cross,
+        with_decis: true,
+        fixed_format: Some(Format::MmSs),
+        countdown: ONE_MINUTE * 5,
+        checkpoints: vec![ONE_MINUTE * 4],
+        ..Settings::default()
+    };
+    let mut c = Clock::<Countdown>::from_config(&settings, ONE_MINUTE * 5);
+    assert_eq!(c.style, Style::Cross);
+    assert!(c.with_decis);
+    assert_eq!(c.get_format(), Format::MmSs);
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_MINUTE * 5);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_MINUTE * 5);
+    assert_eq!(c.get_mode(), &Mode::Initial);
+    // checkpoint
+    c.toggle_pause();
+    let mut fired = None;
+    while fired.is_none() && !c.is_done() {
+        fired = c.tick();
+    }
+    assert_eq!(fired, Some(ONE_MINUTE * 4));
+
+    // continued
+    let c = Clock::<Countdown>::from_config(&settings, ONE_MINUTE * 2);
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_MINUTE * 5);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_MINUTE * 2);
+    assert_eq!(c.get_mode(), &Mode::Pause);
+
+    // presets
+    let settings = Settings {
+        presets: vec![ONE_SECOND, ONE_SECOND * 2],
+        ..settings
+    };
+    let mut c = Clock::<Countdown>::from_config(&settings, ONE_MINUTE * 2);
+    // first preset wins over `countdown` and `current_value`
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND);
+    c.toggle_pause();
+    while Duration::from(*c.get_initial_value()) == ONE_SECOND {
+        c.tick();
+    }
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_SECOND * 2);
+}
+
+#[test]
+fn test_display_value() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 5,
+        current_value: ONE_SECOND * 5,
+        tick_value: ONE_DECI_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_pause();
+    c.tick();
+    // 4.9s
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        Duration::from_millis(4900)
+    );
+    assert_eq!(Duration::from(c.display_value()), ONE_SECOND * 5);
+    let widget = ClockWidget::<Countdown>::new();
+    let height = widget.get_height();
+    let mut expected = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 5,
+        current_value: ONE_SECOND * 5,
+        tick_value: ONE_DECI_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert_eq!(
+        ClockWidget::new().render_to_string(&mut c, 5, height),
+        ClockWidget::new().render_to_string(&mut expected, 5, height)
+    );
+    // 0.1s is not displayed as done
+    while Duration::from(*c.get_current_value()) > ONE_DECI_SECOND {
+        c.tick();
+    }
+    assert_eq!(Duration::from(c.display_value()), ONE_SECOND);
+    assert!(!c.is_done());
+    // done by raw value
+    c.tick();
+    assert!(c.is_done());
+    assert_eq!(Duration::from(c.display_value()), Duration::ZERO);
+
+    // deciseconds
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_SECOND * 5,
+        current_value: ONE_SECOND * 5,
+        tick_value: Duration::from_millis(50),
+        style: Style::default(),
+        with_decis: true,
+    });
+    c.toggle_pause();
+    c.tick();
+    assert_eq!(Duration::from(c.display_value()), ONE_SECOND * 5);
+    c.tick();
+    assert_eq!(
+        Duration::from(c.display_value()),
+        Duration::from_millis(4900)
+    );
+
+    // timer is rounded down
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: Duration::from_millis(4900),
+        tick_value: ONE_DECI_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert_eq!(Duration::from(c.display_value()), ONE_SECOND * 4);
+    c.with_decis = true;
+    assert_eq!(
+        Duration::from(c.display_value()),
+        Duration::from_millis(4900)
+    );
+}
+
+#[test]
+fn test_laps() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    assert!(c.laps().is_empty());
+    assert_eq!(c.last_split(), None);
+    // while running
+    c.toggle_pause();
+    for _ in 0..3 {
+        c.tick();
+    }
+    c.lap();
+    assert!(c.is_running());
+    assert_eq!(c.laps(), &[DurationEx::from(ONE_SECOND * 3)]);
+    assert_eq!(c.last_split(), None);
+    for _ in 0..5 {
+        c.tick();
+    }
+    c.lap();
+    assert_eq!(c.last_split(), Some(DurationEx::from(ONE_SECOND * 5)));
+    // while paused
+    c.toggle_pause();
+    c.lap();
+    assert_eq!(c.get_mode(), &Mode::Pause);
+    assert_eq!(
+        c.laps(),
+        &[
+            DurationEx::from(ONE_SECOND * 3),
+            DurationEx::from(ONE_SECOND * 8),
+            DurationEx::from(ONE_SECOND * 8),
+        ]
+    );
+    assert_eq!(c.last_split(), Some(DurationEx::from(Duration::ZERO)));
+    // reset
+    c.reset();
+    assert!(c.laps().is_empty());
+    assert_eq!(c.last_split(), None);
+}
+
+#[test]
+fn test_set_from_str_countdown() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_SECOND * 30,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    for (s, expected) in [
+        ("90", ONE_SECOND * 90),
+        ("1:30", ONE_SECOND * 90),
+        ("1:30:00", ONE_HOUR + ONE_MINUTE * 30),
+        ("1h30m15s", ONE_HOUR + ONE_MINUTE * 30 + ONE_SECOND * 15),
+    ] {
+        c.set_from_str(s).unwrap();
+        assert_eq!(Duration::from(*c.get_initial_value()), expected, "{}", s);
+        assert_eq!(Duration::from(*c.get_current_value()), expected, "{}", s);
+    }
+    assert_eq!(c.get_format(), Format::HMmSs);
+}
+
+#[test]
+fn test_set_from_str_mode() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    // done -> initial
+    assert!(c.is_done());
+    c.set_from_str("5:00").unwrap();
+    assert_eq!(c.get_mode(), &Mode::Initial);
+    // running -> done
+    c.toggle_pause();
+    c.set_from_str("0").unwrap();
+    assert!(c.is_done());
+    // still running
+    c.set_from_str("5:00").unwrap();
+    c.toggle_pause();
+    c.set_from_str("1:00").unwrap();
+    assert_eq!(c.get_mode(), &Mode::Tick);
+    // while editing, it's updated by exiting edit mode
+    c.toggle_edit();
+    c.set_from_str("0").unwrap();
+    assert!(c.is_edit_mode());
+    c.toggle_edit();
+    assert!(c.is_done());
+}
+
+#[test]
+fn test_set_from_str_timer() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: Duration::ZERO,
+        current_value: Duration::ZERO,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.set_from_str("1h30m15s").unwrap();
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR + ONE_MINUTE * 30 + ONE_SECOND * 15
+    );
+    // initial value of a timer is untouched
+    assert_eq!(Duration::from(*c.get_initial_value()), Duration::ZERO);
+    assert_eq!(c.get_format(), Format::HMmSs);
+}
+
+#[test]
+fn test_set_from_str_invalid() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_SECOND * 30,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    for s in ["1:99", "abc", "", "1h30", "999999", "100h"] {
+        assert!(c.set_from_str(s).is_err(), "{}", s);
+        assert_eq!(Duration::from(*c.get_initial_value()), ONE_MINUTE);
+        assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 30);
+        assert_eq!(c.get_format(), Format::Ss);
+    }
+}
+
+#[test]
+fn test_edit_input_digit_hhmmss() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: ONE_HOUR,
+        current_value: ONE_HOUR,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    // not editing: nothing to do
+    c.edit_input_digit(5);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_HOUR);
+
+    c.toggle_edit();
+    // hours
+    c.edit_next();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Hours, _)));
+    c.edit_input_digit(1);
+    assert_eq!(format!("{}", c.get_current_value()), "11:00:00");
+    c.edit_input_digit(2);
+    assert_eq!(format!("{}", c.get_current_value()), "12:00:00");
+    // seconds
+    c.edit_next();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Seconds, _)));
+    c.edit_input_digit(4);
+    assert_eq!(format!("{}", c.get_current_value()), "12:00:04");
+    c.edit_input_digit(5);
+    assert_eq!(format!("{}", c.get_current_value()), "12:00:45");
+    // minutes
+    c.edit_next();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Minutes, _)));
+    c.edit_input_digit(3);
+    assert_eq!(format!("{}", c.get_current_value()), "12:03:45");
+    c.edit_input_digit(0);
+    assert_eq!(format!("{}", c.get_current_value()), "12:30:45");
+    // invalid digit is ignored
+    c.edit_input_digit(10);
+    assert_eq!(format!("{}", c.get_current_value()), "12:30:45");
+    c.toggle_edit();
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_HOUR * 12 + ONE_MINUTE * 30 + ONE_SECOND * 45
+    );
+}
+
+#[test]
+fn test_edit_input_digit_clamp() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: ONE_SECOND * 5,
+        current_value: ONE_SECOND * 5,
+        tick_value: ONE_DECI_SECOND,
+        style: Style::default(),
+        with_decis: true,
+    });
+    c.toggle_edit();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Seconds, _)));
+    c.edit_input_digit(7);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 57);
+    // 72 -> 59
+    c.edit_input_digit(2);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_SECOND * 59);
+    // decis: single digit only
+    c.edit_prev();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Decis, _)));
+    c.edit_input_digit(3);
+    c.edit_input_digit(8);
+    assert_eq!(
+        Duration::from(*c.get_current_value()),
+        ONE_SECOND * 59 + ONE_DECI_SECOND * 8
+    );
+
+    // hours: 99 max., and `MAX_DURATION` in total
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: MAX_DURATION,
+        current_value: MAX_DURATION,
+        tick_value: ONE_DECI_SECOND,
+        style: Style::default(),
+        with_decis: true,
+    });
+    c.toggle_edit();
+    c.edit_next();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Hours, _)));
+    c.edit_input_digit(9);
+    assert_eq!(Duration::from(*c.get_current_value()), MAX_DURATION);
+    while !matches!(c.get_mode(), Mode::Editable(Time::Decis, _)) {
+        c.edit_next();
+    }
+    c.edit_input_digit(5);
+    assert_eq!(Duration::from(*c.get_current_value()), MAX_DURATION);
+}
+
+#[test]
+fn test_edit_input_digit_countdown() {
+    let mut c = Clock::<Countdown>::new(ClockArgs {
+        initial_value: ONE_MINUTE,
+        current_value: ONE_MINUTE,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    c.toggle_edit();
+    assert!(matches!(c.get_mode(), Mode::Editable(Time::Minutes, _)));
+    // kept within `initial_value` (like `edit_up`)
+    c.edit_input_digit(5);
+    assert_eq!(Duration::from(*c.get_current_value()), ONE_MINUTE);
+    assert_eq!(Duration::from(*c.get_initial_value()), ONE_MINUTE);
+}
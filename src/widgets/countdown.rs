@@ -5,10 +5,12 @@ use ratatui::{
     text::Line,
     widgets::{StatefulWidget, Widget},
 };
-use std::cmp::max;
+use std::{cmp::max, time::Duration};
 
 use crate::{
-    common::Style,
+    common::{DigitFont, Style},
+    config::Settings,
+    constants::{DELTA_SECS, REWIND_VALUE_MS, SNOOZE_VALUE_MS},
     events::{Event, EventHandler},
     utils::center,
     widgets::clock::{self, Clock, ClockWidget},
@@ -17,11 +19,16 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Countdown {
     clock: Clock<clock::Countdown>,
+    // whether a checkpoint has been reached by last ticks
+    just_reached_checkpoint: bool,
 }
 
 impl Countdown {
     pub const fn new(clock: Clock<clock::Countdown>) -> Self {
-        Self { clock }
+        Self {
+            clock,
+            just_reached_checkpoint: false,
+        }
     }
 
     pub fn set_style(&mut self, style: Style) {
@@ -32,9 +39,22 @@ impl Countdown {
         self.clock.with_decis = with_decis;
     }
 
+    pub fn reset_to_defaults(&mut self, settings: &Settings) {
+        self.clock.reset_to_defaults(settings);
+    }
+
     pub fn get_clock(&self) -> &Clock<clock::Countdown> {
         &self.clock
     }
+
+    pub fn just_finished(&mut self) -> bool {
+        self.clock.just_finished()
+    }
+
+    /// Whether a checkpoint has been reached since last call (see `Clock::set_checkpoints`)
+    pub fn just_reached_checkpoint(&mut self) -> bool {
+        std::mem::take(&mut self.just_reached_checkpoint)
+    }
 }
 
 impl EventHandler for Countdown {
@@ -42,7 +62,13 @@ impl EventHandler for Countdown {
         let edit_mode = self.clock.is_edit_mode();
         match event {
             Event::Tick => {
-                self.clock.tick();
+                if self.clock.tick().is_some() {
+                    self.just_reached_checkpoint = true;
+                }
+            }
+            // invalid text (e.g. anything else pasted by accident) is ignored
+            Event::Paste(text) => {
+                let _ = self.clock.set_from_str(text.trim());
             }
             Event::Key(key) if key.code == KeyCode::Char('r') => {
                 self.clock.reset();
@@ -69,6 +95,21 @@ impl EventHandler for Countdown {
                 KeyCode::Down if edit_mode => {
                     self.clock.edit_down();
                 }
+                KeyCode::Char(c) if edit_mode && c.is_ascii_digit() => {
+                    self.clock.edit_input_digit(c as u8 - b'0');
+                }
+                KeyCode::Char('+') if !edit_mode => {
+                    self.clock.apply_delta_secs(DELTA_SECS);
+                }
+                KeyCode::Char('-') if !edit_mode => {
+                    self.clock.apply_delta_secs(-DELTA_SECS);
+                }
+                KeyCode::Char('b') if !edit_mode => {
+                    self.clock.rewind(Duration::from_millis(REWIND_VALUE_MS));
+                }
+                KeyCode::Char('z') if !edit_mode => {
+                    self.clock.snooze(Duration::from_millis(SNOOZE_VALUE_MS));
+                }
                 _ => return Some(event),
             },
             _ => return Some(event),
@@ -77,18 +118,29 @@ impl EventHandler for Countdown {
     }
 }
 
-pub struct CountdownWidget;
+pub struct CountdownWidget {
+    pub mono_digit: bool,
+    pub emphasize_changes: bool,
+    pub font: DigitFont,
+    pub scale: u16,
+    pub background_is_dark: bool,
+}
 
 impl StatefulWidget for CountdownWidget {
     type State = Countdown;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let clock = ClockWidget::new();
+        let clock = ClockWidget::new()
+            .mono_digit(self.mono_digit)
+            .emphasize_changes(self.emphasize_changes)
+            .font(self.font)
+            .vertical_scale(self.scale)
+            .background_is_dark(self.background_is_dark);
         let label = Line::raw((format!("Countdown {}", state.clock.get_mode())).to_uppercase());
 
         let area = center(
             area,
             Constraint::Length(max(
-                clock.get_width(&state.clock.get_format(), state.clock.with_decis),
+                clock.get_width(&state.clock.get_display_format(), state.clock.with_decis),
                 label.width() as u16,
             )),
             Constraint::Length(clock.get_height() + 1 /* height of label */),
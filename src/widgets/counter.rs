@@ -0,0 +1,271 @@
+use crate::{
+    common::{DigitFont, Style},
+    events::{Event, EventHandler},
+    utils::{center, center_horizontal},
+    widgets::{
+        clock::SPACE_WIDTH,
+        clock_elements::{get_digit_height, Digit, DIGIT_WIDTH},
+    },
+};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    text::Line,
+    widgets::{StatefulWidget, Widget},
+};
+use std::cmp::max;
+
+// max. 999999
+const MAX_COUNT: u64 = 999_999;
+
+/// Counts integers (e.g. reps) instead of time
+#[derive(Debug, Clone)]
+pub struct Counter {
+    value: u64,
+    initial: u64,
+    style: Style,
+}
+
+impl Counter {
+    pub fn new(initial: u64, value: u64, style: Style) -> Self {
+        Self {
+            value: value.min(MAX_COUNT),
+            initial: initial.min(MAX_COUNT),
+            style,
+        }
+    }
+
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.value
+    }
+
+    pub fn set_count(&mut self, count: u64) {
+        self.value = count.min(MAX_COUNT);
+    }
+
+    pub fn increment(&mut self) {
+        self.set_count(self.value.saturating_add(1));
+    }
+
+    pub fn decrement(&mut self) {
+        self.set_count(self.value.saturating_sub(1));
+    }
+
+    pub fn reset(&mut self) {
+        self.value = self.initial;
+    }
+}
+
+impl EventHandler for Counter {
+    fn update(&mut self, event: Event) -> Option<Event> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char('+') => {
+                    self.increment();
+                }
+                KeyCode::Char('-') => {
+                    self.decrement();
+                }
+                KeyCode::Char('r') => {
+                    self.reset();
+                }
+                _ => return Some(event),
+            },
+            _ => return Some(event),
+        }
+        None
+    }
+}
+
+/// Renders the count of a `Counter` by digits only
+pub struct CounterWidget {
+    pub font: DigitFont,
+    pub scale: u16,
+    pub background_is_dark: bool,
+}
+
+impl CounterWidget {
+    fn get_digits(count: u64) -> Vec<u64> {
+        count
+            .to_string()
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .map(u64::from)
+            .collect()
+    }
+
+    pub fn get_width(&self, count: u64) -> u16 {
+        let digits = Self::get_digits(count).len() as u16;
+        digits * DIGIT_WIDTH + digits.saturating_sub(1) * SPACE_WIDTH
+    }
+
+    pub fn get_height(&self) -> u16 {
+        get_digit_height(self.font, self.scale)
+    }
+}
+
+impl StatefulWidget for &CounterWidget {
+    type State = Counter;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let count = state.get_count();
+        let symbol = state.style.get_digit_symbol_on(self.background_is_dark);
+        let label = Line::raw("COUNTER");
+
+        let area = center(
+            area,
+            Constraint::Length(max(self.get_width(count), label.width() as u16)),
+            Constraint::Length(self.get_height() + 1 /* height of label */),
+        );
+        let [v1, v2] =
+            Layout::vertical(Constraint::from_lengths([self.get_height(), 1])).areas(area);
+
+        let digits = CounterWidget::get_digits(count);
+        let digits_area = center_horizontal(v1, Constraint::Length(self.get_width(count)));
+        let areas = Layout::horizontal(Constraint::from_lengths(
+            digits.iter().map(|_| DIGIT_WIDTH).collect::<Vec<_>>(),
+        ))
+        .spacing(SPACE_WIDTH)
+        .split(digits_area);
+        let braille = state.style == Style::Dots;
+        for (digit, area) in digits.into_iter().zip(areas.iter()) {
+            Digit::new(digit, false, symbol)
+                .vertical_scale(self.scale)
+                .braille(braille)
+                .half_height(!braille && self.font == DigitFont::HalfHeight)
+                .gradient(state.style == Style::Gradient)
+                .render(*area, buf);
+        }
+        label.centered().render(v2, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use ratatui::crossterm::event::KeyEvent;
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent::from(KeyCode::Char(c)))
+    }
+
+    #[test]
+    fn test_counter() {
+        let mut c = Counter::new(0, 0, Style::default());
+        c.increment();
+        c.increment();
+        assert_eq!(c.get_count(), 2);
+        c.decrement();
+        c.decrement();
+        c.decrement();
+        assert_eq!(c.get_count(), 0);
+        c.set_count(42);
+        assert_eq!(c.get_count(), 42);
+        // max.
+        c.set_count(MAX_COUNT + 1);
+        assert_eq!(c.get_count(), MAX_COUNT);
+        c.increment();
+        assert_eq!(c.get_count(), MAX_COUNT);
+        // reset
+        c.reset();
+        assert_eq!(c.get_count(), 0);
+    }
+
+    #[test]
+    fn test_update() {
+        let mut c = Counter::new(5, 5, Style::default());
+        assert!(c.update(key('+')).is_none());
+        assert_eq!(c.get_count(), 6);
+        c.update(key('-'));
+        c.update(key('-'));
+        assert_eq!(c.get_count(), 4);
+        c.update(key('r'));
+        assert_eq!(c.get_count(), 5);
+        // not handled
+        assert!(c.update(key('q')).is_some());
+        assert!(c.update(Event::Tick).is_some());
+    }
+
+    #[test]
+    fn test_counter_width() {
+        let w = CounterWidget {
+            font: DigitFont::Block,
+            scale: 1,
+            background_is_dark: true,
+        };
+        assert_eq!(w.get_width(0), 5);
+        assert_eq!(w.get_width(42), 5 + 1 + 5);
+        assert_eq!(w.get_width(1234), 4 * 5 + 3);
+    }
+
+    #[test]
+    fn test_counter_render() {
+        let mut c = Counter::new(42, 42, Style::Full);
+        let area = Rect::new(0, 0, 11, 7);
+        let mut b = Buffer::empty(area);
+        let w = CounterWidget {
+            font: DigitFont::Block,
+            scale: 1,
+            background_is_dark: true,
+        };
+        w.render(area, &mut b, &mut c);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "██ ██ █████",
+            "██ ██    ██",
+            "█████ █████",
+            "   ██ ██   ",
+            "   ██ █████",
+            "           ",
+            "  COUNTER  ",
+        ]);
+        assert_eq!(b, expected);
+    }
+
+    #[test]
+    fn test_counter_render_font_scale() {
+        let mut c = Counter::new(1, 1, Style::Full);
+        let render = |font: DigitFont, scale: u16, c: &mut Counter| {
+            let w = CounterWidget {
+                font,
+                scale,
+                background_is_dark: true,
+            };
+            let area = Rect::new(0, 0, 7, w.get_height() + 1);
+            let mut b = Buffer::empty(area);
+            w.render(area, &mut b, c);
+            b
+        };
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "    ██ ",
+            "       ",
+            "COUNTER",
+        ]);
+        assert_eq!(render(DigitFont::Block, 2, &mut c), expected);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "    ██ ",
+            "    ██ ",
+            "    ▀▀ ",
+            "       ",
+            "COUNTER",
+        ]);
+        assert_eq!(render(DigitFont::HalfHeight, 2, &mut c), expected);
+    }
+}
@@ -24,6 +24,7 @@ impl Widget for Footer {
             (Content::Countdown, "[c]ountdown"),
             (Content::Timer, "[t]imer"),
             (Content::Pomodoro, "[p]omodoro"),
+            (Content::Counter, "cou[n]ter"),
         ]);
 
         let [_, area] =
@@ -80,6 +81,8 @@ impl Widget for Footer {
                             Span::from("[,]change style"),
                             Span::from(SPACE),
                             Span::from("[.]toggle deciseconds"),
+                            Span::from(SPACE),
+                            Span::from("[R]estore defaults"),
                         ])),
                     ]),
                     // edit
@@ -89,7 +92,15 @@ impl Widget for Footer {
                             Style::default().add_modifier(Modifier::BOLD),
                         )),
                         Cell::from(Line::from({
-                            if self.edit_mode {
+                            if self.selected_content == Content::Counter {
+                                vec![
+                                    Span::from("[+]count up"),
+                                    Span::from(SPACE),
+                                    Span::from("[-]count down"),
+                                    Span::from(SPACE),
+                                    Span::from("[r]eset"),
+                                ]
+                            } else if self.edit_mode {
                                 vec![
                                     Span::from("[e]dit done"),
                                     Span::from(SPACE),
@@ -102,6 +113,8 @@ impl Widget for Footer {
                                     Span::from(format!("[{}]edit up", scrollbar::VERTICAL.begin)), // ↑
                                     Span::from(SPACE),
                                     Span::from(format!("[{}]edit up", scrollbar::VERTICAL.end)), // ↓,
+                                    Span::from(SPACE),
+                                    Span::from("[0-9]type digit"),
                                 ]
                             } else {
                                 let mut spans = vec![
@@ -115,6 +128,29 @@ impl Widget for Footer {
                                     Span::from(SPACE),
                                     Span::from("[e]dit"),
                                 ];
+                                if matches!(
+                                    self.selected_content,
+                                    Content::Countdown | Content::Timer
+                                ) {
+                                    spans.extend_from_slice(&[
+                                        Span::from(SPACE),
+                                        Span::from("[+ -]add/subtract a minute"),
+                                        Span::from(SPACE),
+                                        Span::from("[b]ack 10s"),
+                                    ]);
+                                }
+                                if self.selected_content == Content::Countdown {
+                                    spans.extend_from_slice(&[
+                                        Span::from(SPACE),
+                                        Span::from("[z]snooze 5m"),
+                                    ]);
+                                }
+                                if self.selected_content == Content::Timer {
+                                    spans.extend_from_slice(&[
+                                        Span::from(SPACE),
+                                        Span::from("[l]ap"),
+                                    ]);
+                                }
                                 if self.selected_content == Content::Pomodoro {
                                     spans.extend_from_slice(&[
                                         Span::from(SPACE),
@@ -0,0 +1,115 @@
+use crate::{
+    common::Style,
+    duration::{ONE_DECI_SECOND, ONE_SECOND},
+    widgets::clock::*,
+};
+use clap::ValueEnum;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+use std::{env, fmt::Debug, fs, path::PathBuf};
+
+/// Set this env. variable to (re-)generate golden files instead of comparing against them,
+/// e.g. `UPDATE_GOLDEN=1 cargo test`
+const UPDATE_GOLDEN: &str = "UPDATE_GOLDEN";
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.txt", name))
+}
+
+/// Compares `actual` against the content of golden file `tests/golden/{name}.txt`
+pub fn assert_golden(name: &str, actual: &str) {
+    let path = golden_path(name);
+    let actual = format!("{}\n", actual);
+    if env::var_os(UPDATE_GOLDEN).is_some() {
+        fs::write(&path, &actual).unwrap();
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing golden file {:?}, run with `{}=1` to create it",
+            path, UPDATE_GOLDEN
+        )
+    });
+    assert_eq!(actual, expected, "golden file {:?}", path);
+}
+
+impl<T> ClockWidget<T>
+where
+    T: Debug + Clone + ClockKind,
+{
+    /// Renders a clock into an off-screen buffer and returns its rows joined by `\n`
+    pub fn render_to_string(self, state: &mut Clock<T>, width: u16, height: u16) -> String {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        self.render(area, &mut buf, state);
+        (0..height)
+            .map(|y| (0..width).map(|x| buf[(x, y)].symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Renders clocks `a` and `b` (copies of them, to keep their state untouched)
+/// with a default `ClockWidget` into buffers of given size and compares these
+/// incl. styles, e.g. to check a clock after a round-trip or refactoring
+/// regardless of internal state which is not displayed.
+pub fn assert_renders_equal<T>(a: &Clock<T>, b: &Clock<T>, width: u16, height: u16)
+where
+    T: Debug + Clone + ClockKind,
+{
+    let render = |clock: &Clock<T>| {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        ClockWidget::new().render(area, &mut buf, &mut clock.clone());
+        buf
+    };
+    assert_eq!(render(a), render(b), "clocks render differently");
+}
+
+#[test]
+fn test_golden_styles() {
+    for style in Style::value_variants() {
+        let mut c = Clock::<Timer>::new(ClockArgs {
+            initial_value: ONE_SECOND * 8,
+            current_value: ONE_SECOND * 8,
+            tick_value: ONE_DECI_SECOND,
+            style: *style,
+            with_decis: false,
+        });
+        let widget = ClockWidget::new();
+        let width = widget.get_width(&c.get_format(), c.with_decis);
+        let height = widget.get_height();
+        let name = style.to_possible_value().unwrap();
+        assert_golden(
+            &format!("digit_8_{}", name.get_name()),
+            &widget.render_to_string(&mut c, width, height),
+        );
+    }
+}
+
+#[test]
+fn test_assert_renders_equal() {
+    let mut c = Clock::<Timer>::new(ClockArgs {
+        initial_value: ONE_SECOND * 90,
+        current_value: ONE_SECOND * 90,
+        tick_value: ONE_SECOND,
+        style: Style::default(),
+        with_decis: false,
+    });
+    let widget = ClockWidget::<Timer>::new();
+    let width = widget.get_width(&c.get_format(), c.with_decis);
+    let height = widget.get_height();
+    assert_renders_equal(&c, &c.clone(), width, height);
+    // same display, but different internal state
+    let mut other = c.clone();
+    other.set_from_str("0").unwrap();
+    other.set_from_str("1:30").unwrap();
+    other.lap();
+    assert_renders_equal(&c, &other, width, height);
+    // different display
+    c.toggle_pause();
+    c.tick();
+    let result = std::panic::catch_unwind(|| assert_renders_equal(&c, &other, width, height));
+    assert!(result.is_err());
+}
@@ -9,12 +9,15 @@ use crate::widgets::progressbar::Progressbar;
 #[derive(Debug, Clone)]
 pub struct Header {
     pub percentage: Option<u16>,
+    pub show_progress_percent: bool,
 }
 
 impl Widget for Header {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if let Some(percentage) = self.percentage {
-            Progressbar::new(percentage).render(area, buf);
+            Progressbar::new(percentage)
+                .show_percent(self.show_progress_percent)
+                .render(area, buf);
         } else {
             Block::new().borders(Borders::TOP).render(area, buf);
         }
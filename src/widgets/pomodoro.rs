@@ -1,9 +1,10 @@
 use crate::{
-    common::Style,
+    common::{DigitFont, Style},
+    config::Settings,
     constants::TICK_VALUE_MS,
     events::{Event, EventHandler},
     utils::center,
-    widgets::clock::{Clock, ClockWidget, Countdown},
+    widgets::clock::{Clock, ClockWidget, Countdown, Format},
 };
 use ratatui::{
     buffer::Buffer,
@@ -61,6 +62,7 @@ pub struct PomodoroArgs {
     pub current_value_pause: Duration,
     pub style: Style,
     pub with_decis: bool,
+    pub fixed_format: Option<Format>,
 }
 
 impl Pomodoro {
@@ -73,24 +75,24 @@ impl Pomodoro {
             current_value_pause,
             style,
             with_decis,
+            fixed_format,
         } = args;
+        let clock = |initial_value: Duration, current_value: Duration| {
+            let mut clock = Clock::<Countdown>::new(ClockArgs {
+                initial_value,
+                current_value,
+                tick_value: Duration::from_millis(TICK_VALUE_MS),
+                style,
+                with_decis,
+            });
+            clock.set_fixed_format(fixed_format);
+            clock
+        };
         Self {
             mode,
             clock_map: ClockMap {
-                work: Clock::<Countdown>::new(ClockArgs {
-                    initial_value: initial_value_work,
-                    current_value: current_value_work,
-                    tick_value: Duration::from_millis(TICK_VALUE_MS),
-                    style,
-                    with_decis,
-                }),
-                pause: Clock::<Countdown>::new(ClockArgs {
-                    initial_value: initial_value_pause,
-                    current_value: current_value_pause,
-                    tick_value: Duration::from_millis(TICK_VALUE_MS),
-                    style,
-                    with_decis,
-                }),
+                work: clock(initial_value_work, current_value_work),
+                pause: clock(initial_value_pause, current_value_pause),
             },
         }
     }
@@ -111,6 +113,10 @@ impl Pomodoro {
         &self.clock_map.pause
     }
 
+    pub fn just_finished(&mut self) -> bool {
+        self.get_clock_mut().just_finished()
+    }
+
     pub fn get_mode(&self) -> &Mode {
         &self.mode
     }
@@ -125,6 +131,11 @@ impl Pomodoro {
         self.clock_map.pause.with_decis = with_decis;
     }
 
+    pub fn reset_to_defaults(&mut self, settings: &Settings) {
+        self.clock_map.work.reset_to_defaults(settings);
+        self.clock_map.pause.reset_to_defaults(settings);
+    }
+
     pub fn next(&mut self) {
         self.mode = match self.mode {
             Mode::Pause => Mode::Work,
@@ -166,6 +177,9 @@ impl EventHandler for Pomodoro {
                 KeyCode::Down if edit_mode => {
                     self.get_clock_mut().edit_down();
                 }
+                KeyCode::Char(c) if edit_mode && c.is_ascii_digit() => {
+                    self.get_clock_mut().edit_input_digit(c as u8 - b'0');
+                }
                 KeyCode::Char('r') => {
                     self.get_clock_mut().reset();
                 }
@@ -177,12 +191,23 @@ impl EventHandler for Pomodoro {
     }
 }
 
-pub struct PomodoroWidget;
+pub struct PomodoroWidget {
+    pub mono_digit: bool,
+    pub emphasize_changes: bool,
+    pub font: DigitFont,
+    pub scale: u16,
+    pub background_is_dark: bool,
+}
 
 impl StatefulWidget for PomodoroWidget {
     type State = Pomodoro;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let clock_widget = ClockWidget::new();
+        let clock_widget = ClockWidget::new()
+            .mono_digit(self.mono_digit)
+            .emphasize_changes(self.emphasize_changes)
+            .font(self.font)
+            .vertical_scale(self.scale)
+            .background_is_dark(self.background_is_dark);
         let label = Line::raw(
             (format!(
                 "Pomodoro {} {}",
@@ -196,7 +221,7 @@ impl StatefulWidget for PomodoroWidget {
             area,
             Constraint::Length(max(
                 clock_widget.get_width(
-                    &state.get_clock().get_format(),
+                    &state.get_clock().get_display_format(),
                     state.get_clock().with_decis,
                 ),
                 label.width() as u16,
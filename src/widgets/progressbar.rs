@@ -9,16 +9,36 @@ use ratatui::{
 #[derive(Debug, Clone)]
 pub struct Progressbar {
     pub percentage: u16,
+    pub show_percent: bool,
 }
 
 impl Progressbar {
     pub fn new(percentage: u16) -> Self {
-        Self { percentage }
+        Self {
+            percentage,
+            show_percent: false,
+        }
+    }
+
+    /// Renders percentage as text (e.g. ` 50%`) at the end of the bar
+    pub fn show_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
     }
 }
 
 impl Widget for Progressbar {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = if self.show_percent {
+            let text = format!(" {}%", self.percentage);
+            let [bar, percent] =
+                Layout::horizontal([Constraint::Fill(0), Constraint::Length(text.len() as u16)])
+                    .areas(area);
+            Span::from(text).render(percent, buf);
+            bar
+        } else {
+            area
+        };
         let [h1, h2] =
             Layout::horizontal([Constraint::Percentage(self.percentage), Constraint::Fill(0)])
                 .areas(area);
@@ -28,3 +48,36 @@ impl Widget for Progressbar {
         Span::from(line::HORIZONTAL.repeat(h2.width as usize)).render(h2, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn render(progressbar: Progressbar) -> String {
+        let area = Rect::new(0, 0, 20, 1);
+        let mut b = Buffer::empty(area);
+        progressbar.render(area, &mut b);
+        (0..area.width).map(|x| b[(x, 0)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_percent() {
+        let actual = render(Progressbar::new(50).show_percent(true));
+        assert!(actual.ends_with(" 50%"));
+        // 50% of remaining width
+        assert_eq!(actual, format!("{}{} 50%", "━".repeat(8), "─".repeat(8)));
+    }
+
+    #[test]
+    fn test_percent_100() {
+        let actual = render(Progressbar::new(100).show_percent(true));
+        assert_eq!(actual, format!("{} 100%", "━".repeat(15)));
+    }
+
+    #[test]
+    fn test_no_percent() {
+        let actual = render(Progressbar::new(50));
+        assert_eq!(actual, format!("{}{}", "━".repeat(10), "─".repeat(10)));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    common::Style,
+    common::{DigitFont, Style},
+    config::Settings,
+    constants::{DELTA_SECS, REWIND_VALUE_MS},
     events::{Event, EventHandler},
     utils::center,
     widgets::clock::{self, Clock, ClockWidget},
@@ -11,7 +13,7 @@ use ratatui::{
     text::Line,
     widgets::{StatefulWidget, Widget},
 };
-use std::cmp::max;
+use std::{cmp::max, time::Duration};
 
 #[derive(Debug, Clone)]
 pub struct Timer {
@@ -31,6 +33,10 @@ impl Timer {
         self.clock.with_decis = with_decis;
     }
 
+    pub fn reset_to_defaults(&mut self, settings: &Settings) {
+        self.clock.reset_to_defaults(settings);
+    }
+
     pub fn get_clock(&self) -> &Clock<clock::Timer> {
         &self.clock
     }
@@ -43,6 +49,10 @@ impl EventHandler for Timer {
             Event::Tick => {
                 self.clock.tick();
             }
+            // invalid text (e.g. anything else pasted by accident) is ignored
+            Event::Paste(text) => {
+                let _ = self.clock.set_from_str(text.trim());
+            }
             Event::Key(key) => match key.code {
                 KeyCode::Char('s') => {
                     self.clock.toggle_pause();
@@ -65,6 +75,21 @@ impl EventHandler for Timer {
                 KeyCode::Down if edit_mode => {
                     self.clock.edit_down();
                 }
+                KeyCode::Char(c) if edit_mode && c.is_ascii_digit() => {
+                    self.clock.edit_input_digit(c as u8 - b'0');
+                }
+                KeyCode::Char('+') if !edit_mode => {
+                    self.clock.apply_delta_secs(DELTA_SECS);
+                }
+                KeyCode::Char('-') if !edit_mode => {
+                    self.clock.apply_delta_secs(-DELTA_SECS);
+                }
+                KeyCode::Char('b') if !edit_mode => {
+                    self.clock.rewind(Duration::from_millis(REWIND_VALUE_MS));
+                }
+                KeyCode::Char('l') if !edit_mode => {
+                    self.clock.lap();
+                }
                 _ => return Some(event),
             },
             _ => return Some(event),
@@ -73,19 +98,37 @@ impl EventHandler for Timer {
     }
 }
 
-pub struct TimerWidget;
+pub struct TimerWidget {
+    pub mono_digit: bool,
+    pub emphasize_changes: bool,
+    pub font: DigitFont,
+    pub scale: u16,
+    pub background_is_dark: bool,
+}
 
 impl StatefulWidget for &TimerWidget {
     type State = Timer;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let clock = &mut state.clock;
-        let clock_widget = ClockWidget::new();
-        let label = Line::raw((format!("Timer {}", clock.get_mode())).to_uppercase());
+        let clock_widget = ClockWidget::new()
+            .mono_digit(self.mono_digit)
+            .emphasize_changes(self.emphasize_changes)
+            .font(self.font)
+            .vertical_scale(self.scale)
+            .background_is_dark(self.background_is_dark);
+        let mut label = format!("Timer {}", clock.get_mode());
+        if let Some(lap) = clock.laps().last() {
+            label.push_str(&format!(" lap {} {}", clock.laps().len(), lap));
+        }
+        if let Some(split) = clock.last_split() {
+            label.push_str(&format!(" (+{})", split));
+        }
+        let label = Line::raw(label.to_uppercase());
 
         let area = center(
             area,
             Constraint::Length(max(
-                clock_widget.get_width(&clock.get_format(), clock.with_decis),
+                clock_widget.get_width(&clock.get_display_format(), clock.with_decis),
                 label.width() as u16,
             )),
             Constraint::Length(clock_widget.get_height() + 1 /* height of label */),